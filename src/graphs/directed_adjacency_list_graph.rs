@@ -0,0 +1,235 @@
+use std::rc::Rc;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter;
+
+use Edge;
+use Graph;
+use GraphError;
+use Weight;
+
+/// A directed graph represented by an adjacency list.
+///
+/// Both the outgoing and incoming edges of each vertex are tracked so that in-degree and out-degree can be
+/// reported separately. Nodes can carry a payload of type `ND` and edges a payload of type `ED`, both of which default
+/// to `()`. Edge payloads are stored per ordered pair of nodes, so parallel edges from one node to another share a
+/// single payload.
+#[derive(Clone)]
+pub struct DirectedAdjacencyListGraph<N, E, ND = (), ED = ()> {
+    vertices: HashMap<N, Vec<Rc<E>>>,
+    in_vertices: HashMap<N, Vec<Rc<E>>>,
+    edges: Vec<Rc<E>>,
+    node_data: HashMap<N, ND>,
+    edge_data: HashMap<(N, N), ED>,
+}
+
+impl<N, E, ND, ED> DirectedAdjacencyListGraph<N, E, ND, ED>
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>
+{
+    /// Returns the number of edges leaving a node.
+    pub fn out_degree(& self, node: &N) -> Result<u32, GraphError<N>> {
+        match self.vertices.get(node) {
+            Some(v) => Ok(v.len() as u32),
+            None => Err(GraphError::NodeNotFound(node.clone()))
+        }
+    }
+
+    /// Returns the number of edges entering a node.
+    pub fn in_degree(& self, node: &N) -> Result<u32, GraphError<N>> {
+        match self.in_vertices.get(node) {
+            Some(v) => Ok(v.len() as u32),
+            None => Err(GraphError::NodeNotFound(node.clone()))
+        }
+    }
+
+    /// The method to get the list of nodes that have an edge into a node, collected from `Graph::predecessors`.
+    pub fn get_node_predecessors(& self, node_id: &N) -> Vec<N> {
+        self.predecessors(node_id).map(|(n, _)| n.clone()).collect()
+    }
+}
+
+impl<N, E, ND, ED> Graph<N, E> for DirectedAdjacencyListGraph<N, E, ND, ED>
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>
+{
+    type NodeData = ND;
+
+    type EdgeData = ED;
+
+    fn new() -> DirectedAdjacencyListGraph<N, E, ND, ED> {
+        DirectedAdjacencyListGraph {
+            vertices: HashMap::new(),
+            in_vertices: HashMap::new(),
+            edges: Vec::new(),
+            node_data: HashMap::new(),
+            edge_data: HashMap::new()
+        }
+    }
+
+    fn add_node(&mut self, vertex_id: N) -> () {
+        self.vertices.insert(vertex_id.clone(), Vec::new());
+        self.in_vertices.insert(vertex_id, Vec::new());
+    }
+
+    fn add_edge(&mut self, source: N, destination: N, weight: E::Weight) -> () {
+        self.add_edge_with_cost(source, destination, weight, Weight::zero());
+    }
+
+    fn add_edge_with_cost(&mut self, source: N, destination: N, weight: E::Weight, cost: E::Weight) -> () {
+        if !self.vertices.contains_key(&source) || !self.vertices.contains_key(&destination) || !weight.is_comparable() {
+            return;
+        }
+
+        let edge: Rc<E> = Rc::new(Edge::with_cost(source.clone(), destination.clone(), weight, cost, true));
+
+        self.vertices.get_mut(&source).unwrap().push(edge.clone());
+        self.in_vertices.get_mut(&destination).unwrap().push(edge.clone());
+        self.edges.push(edge);
+    }
+
+    fn remove_node(&mut self, node_id: &N) -> Result<(), GraphError<N>> {
+        let outgoing = match self.vertices.remove(node_id) {
+            Some(n) => n,
+            None => { return Err(GraphError::NodeNotFound(node_id.clone())); }
+        };
+        let incoming = self.in_vertices.remove(node_id).unwrap_or(Vec::new());
+
+        for e in outgoing.iter() {
+            match self.in_vertices.get_mut(e.target()) {
+                Some(n) => n.retain(|x| !Rc::ptr_eq(x, e)),
+                None => ()
+            }
+        }
+
+        for e in incoming.iter() {
+            match self.vertices.get_mut(e.source()) {
+                Some(n) => n.retain(|x| !Rc::ptr_eq(x, e)),
+                None => ()
+            }
+        }
+
+        self.edges.retain(|e| *e.source() != *node_id && *e.target() != *node_id);
+        self.node_data.remove(node_id);
+        self.edge_data.retain(|&(ref source, ref target), _| *source != *node_id && *target != *node_id);
+
+        Ok(())
+    }
+
+    fn remove_edge(&mut self, source: &N, destination: &N) -> Result<(), GraphError<N>> {
+        if !self.vertices.contains_key(destination) {
+            return Err(GraphError::NodeNotFound(destination.clone()));
+        }
+
+        let edge = match self.vertices.get_mut(source) {
+            Some(n) => {
+                match n.iter().position(|e| *e.target() == *destination) {
+                    Some(i) => n.remove(i),
+                    None => { return Err(GraphError::EdgeNotFound(source.clone(), destination.clone())); }
+                }
+            },
+            None => { return Err(GraphError::NodeNotFound(source.clone())); }
+        };
+
+        match self.in_vertices.get_mut(destination) {
+            Some(n) => n.retain(|x| !Rc::ptr_eq(x, &edge)),
+            None => ()
+        }
+
+        self.edges.retain(|x| !Rc::ptr_eq(x, &edge));
+
+        if !self.is_adjacent(source, destination) {
+            self.edge_data.remove(&(source.clone(), destination.clone()));
+        }
+
+        Ok(())
+    }
+
+    fn nodes<'a>(&'a self) -> Box<Iterator<Item=&'a N> + 'a> {
+        Box::new(self.vertices.keys())
+    }
+
+    fn neighbours<'a>(&'a self, node_id: &N) -> Box<Iterator<Item=(&'a N, &'a E)> + 'a> {
+        match self.vertices.get(node_id) {
+            Some(n) => Box::new(n.iter().map(|e| (e.target(), &**e))),
+            None => Box::new(iter::empty())
+        }
+    }
+
+    fn predecessors<'a>(&'a self, node_id: &N) -> Box<Iterator<Item=(&'a N, &'a E)> + 'a> {
+        match self.in_vertices.get(node_id) {
+            Some(n) => Box::new(n.iter().map(|e| (e.source(), &**e))),
+            None => Box::new(iter::empty())
+        }
+    }
+
+    fn get_edge(& self, source: &N, destination: &N) -> Result<E, GraphError<N>> {
+        match self.vertices.get(source) {
+            Some(n) => {
+                for e in n.iter() {
+                    if *e.target() == *destination {
+                        return Ok((**e).clone());
+                    }
+                }
+
+                Err(GraphError::EdgeNotFound(source.clone(), destination.clone()))
+            },
+            None => Err(GraphError::NodeNotFound(source.clone()))
+        }
+    }
+
+    fn edges<'a>(&'a self) -> Box<Iterator<Item=&'a E> + 'a> {
+        Box::new(self.edges.iter().map(|e| &**e))
+    }
+
+    fn is_adjacent(& self, source: &N, destination: &N) -> bool {
+        match self.vertices.get(source) {
+            Some(n) => n.iter().any(|e| *e.target() == *destination),
+            None => false
+        }
+    }
+
+    fn is_node_in_graph(& self, node: &N) -> bool {
+        self.vertices.contains_key(node)
+    }
+
+    /// Returns the total degree of a node, that is the sum of its in-degree and out-degree.
+    fn degree(& self, node: &N) -> Result<u32, GraphError<N>> {
+        let out_degree = try!(self.out_degree(node));
+        let in_degree = try!(self.in_degree(node));
+
+        Ok(out_degree + in_degree)
+    }
+
+    fn node_data(& self, node: &N) -> Option<&ND> {
+        self.node_data.get(node)
+    }
+
+    fn node_data_mut(&mut self, node: &N) -> Option<&mut ND> {
+        self.node_data.get_mut(node)
+    }
+
+    fn set_node_data(&mut self, node: &N, data: ND) -> Result<Option<ND>, GraphError<N>> {
+        if !self.is_node_in_graph(node) {
+            return Err(GraphError::NodeNotFound(node.clone()));
+        }
+
+        Ok(self.node_data.insert(node.clone(), data))
+    }
+
+    fn edge_data(& self, source: &N, destination: &N) -> Option<&ED> {
+        self.edge_data.get(&(source.clone(), destination.clone()))
+    }
+
+    fn edge_data_mut(&mut self, source: &N, destination: &N) -> Option<&mut ED> {
+        self.edge_data.get_mut(&(source.clone(), destination.clone()))
+    }
+
+    fn set_edge_data(&mut self, source: &N, destination: &N, data: ED) -> Result<Option<ED>, GraphError<N>> {
+        if !self.is_adjacent(source, destination) {
+            return Err(GraphError::EdgeNotFound(source.clone(), destination.clone()));
+        }
+
+        Ok(self.edge_data.insert((source.clone(), destination.clone()), data))
+    }
+}
@@ -3,7 +3,9 @@
 pub use self::weighted_edge::WeightedEdge;
 pub use self::unweighted_edge::UnweightedEdge;
 pub use self::undirected_adjacency_list_graph::UndirectedAdjacencyListGraph;
+pub use self::directed_adjacency_list_graph::DirectedAdjacencyListGraph;
 
 mod weighted_edge;
 mod unweighted_edge;
-mod undirected_adjacency_list_graph;
\ No newline at end of file
+mod undirected_adjacency_list_graph;
+mod directed_adjacency_list_graph;
\ No newline at end of file
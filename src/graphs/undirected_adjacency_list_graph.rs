@@ -1,25 +1,58 @@
 use std::rc::Rc;
 use std::collections::HashMap;
 use std::hash::Hash;
+use std::iter;
 
 use Edge;
 use Graph;
+use GraphError;
+use Weight;
 
 /// An undirected graph represented by an adjacency list.
+///
+/// Nodes can carry a payload of type `ND` and edges a payload of type `ED`, both of which default to `()`. Edge payloads
+/// are stored per pair of nodes, so parallel edges between the same two nodes share a single payload.
 #[derive(Clone)]
-pub struct UndirectedAdjacencyListGraph<N, E> {
+pub struct UndirectedAdjacencyListGraph<N, E, ND = (), ED = ()> {
     vertices: HashMap<N, Vec<Rc<E>>>,
     edges: Vec<Rc<E>>,
+    node_data: HashMap<N, ND>,
+    edge_data: HashMap<(N, N), ED>,
 }
 
-impl<N, E> Graph<N, E> for UndirectedAdjacencyListGraph<N, E> 
+impl<N, E, ND, ED> UndirectedAdjacencyListGraph<N, E, ND, ED>
+    where N: Eq + Clone + Hash
+{
+    /// Returns the key under which the payload of the edge between two nodes is stored, if there is one.
+    fn edge_data_key(& self, source: &N, destination: &N) -> Option<(N, N)> {
+        let key = (source.clone(), destination.clone());
+        if self.edge_data.contains_key(&key) {
+            return Some(key);
+        }
+        
+        let reverse_key = (destination.clone(), source.clone());
+        if self.edge_data.contains_key(&reverse_key) {
+            return Some(reverse_key);
+        }
+        
+        None
+    }
+}
+
+impl<N, E, ND, ED> Graph<N, E> for UndirectedAdjacencyListGraph<N, E, ND, ED> 
     where N: Eq + Clone + Hash,
           E: Eq + Clone + Hash + Edge<N>
 {
-    fn new() -> UndirectedAdjacencyListGraph<N, E> {
+    type NodeData = ND;
+    
+    type EdgeData = ED;
+    
+    fn new() -> UndirectedAdjacencyListGraph<N, E, ND, ED> {
         UndirectedAdjacencyListGraph {
             vertices: HashMap::new(),
-            edges: Vec::new()
+            edges: Vec::new(),
+            node_data: HashMap::new(),
+            edge_data: HashMap::new()
         }
     }
     
@@ -27,8 +60,16 @@ impl<N, E> Graph<N, E> for UndirectedAdjacencyListGraph<N, E>
         self.vertices.insert(vertex_id, Vec::new());
     }
     
-    fn add_edge(&mut self, source: N, destination: N, weight: i32) -> () {
-        let edge: Rc<E> = Rc::new(Edge::new(source.clone(), destination.clone(), weight, false));
+    fn add_edge(&mut self, source: N, destination: N, weight: E::Weight) -> () {
+        self.add_edge_with_cost(source, destination, weight, Weight::zero());
+    }
+    
+    fn add_edge_with_cost(&mut self, source: N, destination: N, weight: E::Weight, cost: E::Weight) -> () {
+        if !self.vertices.contains_key(&source) || !self.vertices.contains_key(&destination) || !weight.is_comparable() {
+            return;
+        }
+        
+        let edge: Rc<E> = Rc::new(Edge::with_cost(source.clone(), destination.clone(), weight, cost, false));
     
         {
             match self.vertices.get_mut(&source) {
@@ -37,7 +78,7 @@ impl<N, E> Graph<N, E> for UndirectedAdjacencyListGraph<N, E>
             };
         }
         
-        let reverse_edge: Rc<E> = Rc::new(Edge::new(destination.clone(), source.clone(), weight, false));
+        let reverse_edge: Rc<E> = Rc::new(Edge::with_cost(destination.clone(), source.clone(), weight, cost, false));
 
         {
             match self.vertices.get_mut(&destination) {
@@ -49,55 +90,107 @@ impl<N, E> Graph<N, E> for UndirectedAdjacencyListGraph<N, E>
         self.edges.push(edge);
     }
     
-    fn get_nodes(&self) -> Vec<N> {
-        self.vertices.keys().map(|x| x.clone()).collect()
+    fn remove_node(&mut self, node_id: &N) -> Result<(), GraphError<N>> {
+        let incident = match self.vertices.remove(node_id) {
+            Some(n) => n,
+            None => { return Err(GraphError::NodeNotFound(node_id.clone())); }
+        };
+        
+        for e in incident.iter() {
+            let neighbour = e.get_target();
+            
+            if neighbour != *node_id {
+                match self.vertices.get_mut(&neighbour) {
+                    Some(n) => { remove_first_edge(n, node_id, e.get_weight()); },
+                    None => ()
+                }
+            }
+        }
+        
+        self.edges.retain(|e| *e.source() != *node_id && *e.target() != *node_id);
+        self.node_data.remove(node_id);
+        self.edge_data.retain(|&(ref source, ref target), _| *source != *node_id && *target != *node_id);
+        
+        Ok(())
     }
-
-    fn get_node_neighbours(& self, node_id: &N) -> Vec<N> {
-        let node = self.vertices.get(node_id);
-        let mut neighbours = Vec::new();
+    
+    fn remove_edge(&mut self, source: &N, destination: &N) -> Result<(), GraphError<N>> {
+        if !self.vertices.contains_key(destination) {
+            return Err(GraphError::NodeNotFound(destination.clone()));
+        }
         
-        match node {
+        let weight = match self.vertices.get_mut(source) {
             Some(n) => {
-                for e in n.iter() {
-                    neighbours.push(e.clone().get_target());
+                match n.iter().position(|e| *e.target() == *destination) {
+                    Some(i) => n.remove(i).get_weight(),
+                    None => { return Err(GraphError::EdgeNotFound(source.clone(), destination.clone())); }
                 }
-                
-                neighbours
             },
-            None => neighbours
+            None => { return Err(GraphError::NodeNotFound(source.clone())); }
+        };
+        
+        match self.vertices.get_mut(destination) {
+            Some(n) => { remove_first_edge(n, source, weight); },
+            None => ()
         }
+        
+        let position = self.edges.iter().position(|e| e.get_weight() == weight &&
+            ((*e.source() == *source && *e.target() == *destination) ||
+             (*e.source() == *destination && *e.target() == *source)));
+        
+        match position {
+            Some(i) => { self.edges.remove(i); },
+            None => ()
+        }
+        
+        if !self.is_adjacent(source, destination) {
+            match self.edge_data_key(source, destination) {
+                Some(key) => { self.edge_data.remove(&key); },
+                None => ()
+            }
+        }
+        
+        Ok(())
     }
     
-    fn get_edge(& self, source: &N, destination: &N) -> Result<E, String> {
+    fn nodes<'a>(&'a self) -> Box<Iterator<Item=&'a N> + 'a> {
+        Box::new(self.vertices.keys())
+    }
+
+    fn neighbours<'a>(&'a self, node_id: &N) -> Box<Iterator<Item=(&'a N, &'a E)> + 'a> {
+        match self.vertices.get(node_id) {
+            Some(n) => Box::new(n.iter().map(|e| (e.target(), &**e))),
+            None => Box::new(iter::empty())
+        }
+    }
+    
+    fn predecessors<'a>(&'a self, node_id: &N) -> Box<Iterator<Item=(&'a N, &'a E)> + 'a> {
+        self.neighbours(node_id)
+    }
+    
+    fn get_edge(& self, source: &N, destination: &N) -> Result<E, GraphError<N>> {
         let node = self.vertices.get(source);
         
         match node {
             Some(n) => {
                 let mut edge = None;
                 for e in n.iter() {
-                    if e.clone().get_target() == *destination {
+                    if *e.target() == *destination {
                         edge = Some(e.clone());
                     }
                 }
                 
                 match edge {
                     Some(e) => { return Ok( (*e).clone()); },
-                    None => { return Err("No edge exists between the provided vertices.".to_string()); }
+                    None => { return Err(GraphError::EdgeNotFound(source.clone(), destination.clone())); }
                 }
             },
-            None => { return Err("Node does not exists in graph.".to_string()); }
+            None => { return Err(GraphError::NodeNotFound(source.clone())); }
         }
     }
     
-    fn get_edges(&self) -> Vec<E> {
-        let mut result = Vec::new();
-        
-        for edge in self.edges.iter() {
-            result.push( (*(*edge)).clone() );
-        }
-        
-        result
+    fn edges<'a>(&'a self) -> Box<Iterator<Item=&'a E> + 'a> {
+        Box::new(self.edges.iter().map(|e| &**e))
     }
     
     fn is_adjacent(& self, source: &N, destination: &N) -> bool {
@@ -106,7 +199,7 @@ impl<N, E> Graph<N, E> for UndirectedAdjacencyListGraph<N, E>
         match node {
             Some(n) => {
                 for e in n.iter() {
-                    if e.clone().get_target() == *destination {
+                    if *e.target() == *destination {
                         return true;
                     }
                 }
@@ -124,12 +217,66 @@ impl<N, E> Graph<N, E> for UndirectedAdjacencyListGraph<N, E>
         }
     }
     
-    fn degree(& self, node: &N) -> Result<u32, String> {
+    fn degree(& self, node: &N) -> Result<u32, GraphError<N>> {
         let vertex = self.vertices.get(node);
         
         match vertex {
             Some(v) => Ok(v.len() as u32),
-            None => Err("An error occured while getting the vertex degree.".to_string())
+            None => Err(GraphError::NodeNotFound(node.clone()))
+        }
+    }
+    
+    fn node_data(& self, node: &N) -> Option<&ND> {
+        self.node_data.get(node)
+    }
+    
+    fn node_data_mut(&mut self, node: &N) -> Option<&mut ND> {
+        self.node_data.get_mut(node)
+    }
+    
+    fn set_node_data(&mut self, node: &N, data: ND) -> Result<Option<ND>, GraphError<N>> {
+        if !self.is_node_in_graph(node) {
+            return Err(GraphError::NodeNotFound(node.clone()));
         }
+        
+        Ok(self.node_data.insert(node.clone(), data))
     }
-}
\ No newline at end of file
+    
+    fn edge_data(& self, source: &N, destination: &N) -> Option<&ED> {
+        match self.edge_data_key(source, destination) {
+            Some(key) => self.edge_data.get(&key),
+            None => None
+        }
+    }
+    
+    fn edge_data_mut(&mut self, source: &N, destination: &N) -> Option<&mut ED> {
+        match self.edge_data_key(source, destination) {
+            Some(key) => self.edge_data.get_mut(&key),
+            None => None
+        }
+    }
+    
+    fn set_edge_data(&mut self, source: &N, destination: &N, data: ED) -> Result<Option<ED>, GraphError<N>> {
+        if !self.is_adjacent(source, destination) {
+            return Err(GraphError::EdgeNotFound(source.clone(), destination.clone()));
+        }
+        
+        let key = match self.edge_data_key(source, destination) {
+            Some(key) => key,
+            None => (source.clone(), destination.clone())
+        };
+        
+        Ok(self.edge_data.insert(key, data))
+    }
+}
+
+/// Removes the first edge in an adjacency list that leads to `target` with the given weight.
+fn remove_first_edge<N, E>(adjacency: &mut Vec<Rc<E>>, target: &N, weight: E::Weight) -> ()
+    where N: Eq,
+          E: Edge<N>
+{
+    match adjacency.iter().position(|e| *e.target() == *target && e.get_weight() == weight) {
+        Some(i) => { adjacency.remove(i); },
+        None => ()
+    }
+}
@@ -3,7 +3,7 @@ use Edge;
 /// A default implementation of a weighted edge that can be used in graph implementations.
 ///
 /// The weight parameter is ignored when constructing an edge and all edges have weight = 1.
-#[derive(Clone, Hash)]
+#[derive(Debug, Clone, Hash)]
 pub struct UnweightedEdge<N> {
     source: N,
     target: N,
@@ -14,6 +14,8 @@ pub struct UnweightedEdge<N> {
 impl<N> Edge<N> for UnweightedEdge<N>
     where N: Clone
 {
+    type Weight = i32;
+    
     fn new(source: N, target: N, weight: i32, directed: bool) -> Self {
         UnweightedEdge {
             source: source,
@@ -27,12 +29,12 @@ impl<N> Edge<N> for UnweightedEdge<N>
         self.weight
     }
     
-    fn get_source(&self) -> N {
-        self.source.clone()
+    fn source(&self) -> &N {
+        &self.source
     }
     
-    fn get_target(&self) -> N {
-        self.target.clone()
+    fn target(&self) -> &N {
+        &self.target
     }
     
     fn is_directed(&self) -> bool {
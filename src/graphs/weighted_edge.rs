@@ -1,36 +1,56 @@
+use std::hash::Hash;
+use std::hash::Hasher;
+
 use Edge;
+use Weight;
 
 /// A default implementation of a weighted edge that can be used in graph implementations.
-#[derive(Clone, Hash)]
-pub struct WeightedEdge<N> {
+///
+/// The weight type defaults to `i32` but can be any type that implements `Weight`. Each edge also stores the cost of
+/// sending one unit of flow along it, of the same type as the weight, which is zero unless set with `with_cost`.
+#[derive(Debug, Clone)]
+pub struct WeightedEdge<N, W = i32> {
     source: N,
     target: N,
-    weight: i32,
+    weight: W,
+    cost: W,
     directed: bool
 }
 
-impl<N> Edge<N> for WeightedEdge<N>
-    where N: Clone
+impl<N, W> Edge<N> for WeightedEdge<N, W>
+    where N: Clone,
+          W: Weight
 {
-    fn new(source: N, target: N, weight: i32, directed: bool) -> Self {
+    type Weight = W;
+    
+    fn new(source: N, target: N, weight: W, directed: bool) -> Self {
+        Edge::with_cost(source, target, weight, W::zero(), directed)
+    }
+    
+    fn with_cost(source: N, target: N, weight: W, cost: W, directed: bool) -> Self {
         WeightedEdge {
             source: source,
             target: target,
             weight: weight,
+            cost: cost,
             directed: directed
         }
     }
     
-    fn get_weight(&self) -> i32 {
+    fn get_weight(&self) -> W {
         self.weight
     }
     
-    fn get_source(&self) -> N {
-        self.source.clone()
+    fn get_cost(&self) -> W {
+        self.cost
     }
     
-    fn get_target(&self) -> N {
-        self.target.clone()
+    fn source(&self) -> &N {
+        &self.source
+    }
+    
+    fn target(&self) -> &N {
+        &self.target
     }
     
     fn is_directed(&self) -> bool {
@@ -38,12 +58,34 @@ impl<N> Edge<N> for WeightedEdge<N>
     }
 }
 
-impl<N> PartialEq for WeightedEdge<N>
-    where N: Eq
+/// Weights and costs that are not comparable with themselves, such as NaN floats, are treated as equal to each other so
+/// that the comparison stays reflexive and `Eq` holds.
+impl<N, W> PartialEq for WeightedEdge<N, W>
+    where N: Eq,
+          W: Weight
 {
-    fn eq(&self, other: &WeightedEdge<N>) -> bool {
-        self.source == other.source && self.target == other.target && self.weight == other.weight && self.directed == other.directed
+    fn eq(&self, other: &WeightedEdge<N, W>) -> bool {
+        let same_weight = is_same_weight(&self.weight, &other.weight) && is_same_weight(&self.cost, &other.cost);
+        
+        self.source == other.source && self.target == other.target && same_weight && self.directed == other.directed
     }
 }
 
-impl<N> Eq for WeightedEdge<N> where N: Eq { }
\ No newline at end of file
+impl<N, W> Eq for WeightedEdge<N, W> where N: Eq, W: Weight { }
+
+/// The weight and cost are left out of the hash so that weights which are not `Hash`, such as floats, can be used.
+impl<N, W> Hash for WeightedEdge<N, W>
+    where N: Hash
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.source.hash(state);
+        self.target.hash(state);
+        self.directed.hash(state);
+    }
+}
+
+fn is_same_weight<W>(a: &W, b: &W) -> bool
+    where W: Weight
+{
+    *a == *b || (!a.is_comparable() && !b.is_comparable())
+}
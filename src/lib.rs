@@ -9,19 +9,33 @@ use fibonacci_heap::FibonacciHeap;
 use std::vec::Vec;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::collections::VecDeque;
 use std::hash::Hash;
-use std::i32;
 use std::cmp::Eq;
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
 
 
 pub mod graphs;
 
 /// A struct used to store data used during the execution of Dijkstra's algorithm
 #[derive(Clone)]
-struct MetadataDijsktra<N> {
+///
+/// The distance is `None` until the node has been reached, so that every weight, including `Weight::max_value()`, can be
+/// a real distance.
+struct MetadataDijsktra<N, W> {
     predecessor: Option<N>,
     visited: bool,
-    distance: i32
+    distance: Option<W>
+}
+
+/// A struct used to store data used during the execution of Prim's algorithm
+#[derive(Clone)]
+struct MetadataPrim<'a, E: 'a> {
+    edge: Option<&'a E>,
+    in_tree: bool
 }
 
 /// A struct used to store data used during the execution of the K core decomposition algorithm
@@ -40,6 +54,12 @@ pub trait Graph<N, E>
     where N: Eq + Clone + Hash,
           E: Eq + Clone + Hash + Edge<N>
 {
+    /// The type of the payload that can be attached to each node.
+    type NodeData;
+    
+    /// The type of the payload that can be attached to each edge.
+    type EdgeData;
+    
     /// Creates a new instance of the graph.
     fn new() -> Self;
     
@@ -47,19 +67,60 @@ pub trait Graph<N, E>
     fn add_node(&mut self, node_id: N) -> ();
     
     /// The method to add an edge to the graph between two nodes, specifying a weight.
-    fn add_edge(&mut self, source: N, destination: N, weight: i32) -> ();
+    ///
+    /// The edge is not added if either node is not in the graph, or if its weight is not comparable with itself, such as
+    /// a NaN float.
+    fn add_edge(&mut self, source: N, destination: N, weight: E::Weight) -> ();
+    
+    /// The method to add an edge to the graph between two nodes, specifying a weight and the cost of sending one unit of
+    /// flow along it.
+    ///
+    /// The cost is dropped if the edge type does not store one. The edge is not added in the same cases as `add_edge`.
+    fn add_edge_with_cost(&mut self, source: N, destination: N, weight: E::Weight, cost: E::Weight) -> ();
+    
+    /// The method to remove a node from the graph, along with every edge incident to it.
+    ///
+    /// Returns `GraphError::NodeNotFound` if the node does not exist in the graph.
+    fn remove_node(&mut self, node_id: &N) -> Result<(), GraphError<N>>;
+    
+    /// The method to remove the edge between two nodes.
+    ///
+    /// Returns `GraphError::NodeNotFound` or `GraphError::EdgeNotFound` if either node or the edge between them does not exist.
+    fn remove_edge(&mut self, source: &N, destination: &N) -> Result<(), GraphError<N>>;
+    
+    /// The method to iterate over the IDs of all nodes in the graph.
+    fn nodes<'a>(&'a self) -> Box<Iterator<Item=&'a N> + 'a>;
+    
+    /// The method to iterate over the nodes that are adjacent to a node, paired with the edge that leads to each of them.
+    ///
+    /// The iterator is empty if the node does not exist in the graph.
+    fn neighbours<'a>(&'a self, node_id: &N) -> Box<Iterator<Item=(&'a N, &'a E)> + 'a>;
+    
+    /// The method to iterate over the nodes that have an edge leading to a node, paired with that edge.
+    ///
+    /// For undirected graphs this is the same as `neighbours`. The iterator is empty if the node does not exist in the graph.
+    fn predecessors<'a>(&'a self, node_id: &N) -> Box<Iterator<Item=(&'a N, &'a E)> + 'a>;
+    
+    /// The method to iterate over the edge set in the graph.
+    fn edges<'a>(&'a self) -> Box<Iterator<Item=&'a E> + 'a>;
     
     /// The method to return a vector of IDs of all nodes in the graph.
-    fn get_nodes(&self) -> Vec<N>;
+    fn get_nodes(&self) -> Vec<N> {
+        self.nodes().cloned().collect()
+    }
     
     /// The method to get the list of nodes that are adjacent to a node.
-    fn get_node_neighbours(& self, node_id: &N) -> Vec<N>;
+    fn get_node_neighbours(& self, node_id: &N) -> Vec<N> {
+        self.neighbours(node_id).map(|(n, _)| n.clone()).collect()
+    }
     
     /// The method to get edge between two nodes.
-    fn get_edge(& self, source: &N, destination: &N) -> Result<E, String>;
+    fn get_edge(& self, source: &N, destination: &N) -> Result<E, GraphError<N>>;
     
     /// The method to get the edge set in the graph.
-    fn get_edges(&self) -> Vec<E>;
+    fn get_edges(&self) -> Vec<E> {
+        self.edges().cloned().collect()
+    }
     
     /// The method to check if two nodes are adjacent.
     fn is_adjacent(& self, source: &N, destination: &N) -> bool;
@@ -68,369 +129,3058 @@ pub trait Graph<N, E>
     fn is_node_in_graph(& self, node: &N) -> bool;
     
     /// The method to return the degree of a node.
-    fn degree(& self, node: &N) -> Result<u32, String>;
+    fn degree(& self, node: &N) -> Result<u32, GraphError<N>>;
+    
+    /// The method to get the payload attached to a node.
+    ///
+    /// Returns `None` if the node does not exist or has no payload.
+    fn node_data(& self, node: &N) -> Option<&Self::NodeData>;
+    
+    /// The method to mutably borrow the payload attached to a node.
+    fn node_data_mut(&mut self, node: &N) -> Option<&mut Self::NodeData>;
+    
+    /// The method to attach a payload to a node, returning the payload it replaced.
+    fn set_node_data(&mut self, node: &N, data: Self::NodeData) -> Result<Option<Self::NodeData>, GraphError<N>>;
+    
+    /// The method to get the payload attached to the edge between two nodes.
+    ///
+    /// Edge payloads are keyed by the pair of nodes rather than stored with each edge, so parallel edges between the same
+    /// pair of nodes share a single payload, which is only dropped once the last of them is removed. Returns `None` if
+    /// there is no edge between the nodes or it has no payload.
+    fn edge_data(& self, source: &N, destination: &N) -> Option<&Self::EdgeData>;
+    
+    /// The method to mutably borrow the payload attached to the edge between two nodes.
+    ///
+    /// Changes are seen through every parallel edge between the same pair of nodes.
+    fn edge_data_mut(&mut self, source: &N, destination: &N) -> Option<&mut Self::EdgeData>;
+    
+    /// The method to attach a payload to the edge between two nodes, returning the payload it replaced.
+    ///
+    /// The payload is shared by every parallel edge between the same pair of nodes.
+    fn set_edge_data(&mut self, source: &N, destination: &N, data: Self::EdgeData) -> Result<Option<Self::EdgeData>, GraphError<N>>;
+    
+    /// The method to add a node to the graph together with its payload.
+    fn add_node_with_data(&mut self, node_id: N, data: Self::NodeData) -> () {
+        self.add_node(node_id.clone());
+        let _ = self.set_node_data(&node_id, data);
+    }
+    
+    /// The method to add an edge to the graph between two nodes, specifying a weight and a payload.
+    ///
+    /// If the nodes are already joined by an edge its payload is replaced, as parallel edges share a single payload.
+    fn add_edge_with_data(&mut self, source: N, destination: N, weight: E::Weight, data: Self::EdgeData) -> () {
+        self.add_edge(source.clone(), destination.clone(), weight);
+        let _ = self.set_edge_data(&source, &destination, data);
+    }
 
-    /// Performs Dijkstra's shortest path algorithm on the graph.
+    /// Performs a breadth first search of the graph starting at a node.
     ///
-    /// Returns the `GraphPath` between the two vertices and will end prematurely once the path has been found.
-    /// Returns an error string if an error occured during the algorithm execution.
-    /// 
-    /// This algorithm runs in worst case O(V<sup>2</sup>) time.
-    fn dijkstras_shortest_path(& self, source: &N, destination: &N) -> Result<GraphPath<N>, String> where Self: Sized {
-        if !self.is_node_in_graph(source) || !self.is_node_in_graph(destination) {
-            return Err("The start or target node does not exist in the graph.".to_string());
-        }
+    /// Returns a lazy iterator that yields a `TraversalStep` for each node reachable from the start node, in the order in which
+    /// they are discovered. The iterator is empty if the start node does not exist in the graph.
+    fn bfs<'a>(&'a self, start: &N) -> BreadthFirstSearch<'a, N, E, Self> where Self: Sized {
+        let mut queue = VecDeque::new();
+        let mut discovered = HashSet::new();
         
-        let mut metadata: HashMap<N, MetadataDijsktra<N>>;
-        let mut heap: FibonacciHeap<i32, N> = FibonacciHeap::new();
-        
-        metadata = try!(create_dijkstra_metadata(&self.get_nodes(), &mut heap, source));
-
-        while heap.minimum().is_some() {
-            match metadata.get(destination) {
-                Some(ref x) => {
-                    if x.visited {
-                        break;
-                    }
-                },
-                None => return Err("There was an error retrieving metadata about the target node.".to_string())
-            }
-            
-            let min_id: N = heap.extract_min().unwrap().1;
-            
-            match metadata.get_mut(&min_id) {
-                Some(ref mut x) => x.visited = true,
-                None => return Err("There was an error retrieving metadata about a node.".to_string())
-            }
-            
-            try!(perform_edge_relaxation(self, &mut metadata, &mut heap, &min_id));
+        if self.is_node_in_graph(start) {
+            queue.push_back(TraversalStep::new(start.clone(), 0, None));
+            discovered.insert(start.clone());
         }
         
-        backtrack_vertex_predecessor(&metadata, source, destination)
+        BreadthFirstSearch {
+            graph: self,
+            queue: queue,
+            discovered: discovered,
+            edge: PhantomData
+        }
     }
     
-    /// Performs Dijkstra's shortest path algorithm on the graph.
+    /// Performs a depth first search of the graph starting at a node.
     ///
-    /// Returns a `HashMap` of target vertices to the `GraphPath` between the vertex and the target vertex.
-    /// The `HashMap` will be empty if an error occured.
-    /// 
-    /// This algorithm runs in worst case O(V<sup>2</sup>) time.
-    fn dijkstras_shortest_paths(& self, source: &N) -> Result<HashMap<N, GraphPath<N>>, String> where Self: Sized {
-        if !self.is_node_in_graph(source) {
-            return Err("The start node does not exist in the graph.".to_string());
-        }
-        
-        let mut metadata: HashMap<N, MetadataDijsktra<N>>;
-        let mut heap: FibonacciHeap<i32, N> = FibonacciHeap::new();
-        let nodes: Vec<N> = self.get_nodes();
-        
-        metadata = try!(create_dijkstra_metadata(&nodes, &mut heap, source));
+    /// Returns a lazy iterator that yields a `TraversalStep` for each node reachable from the start node, in the order in which
+    /// they are discovered. The iterator is empty if the start node does not exist in the graph.
+    fn dfs<'a>(&'a self, start: &N) -> DepthFirstSearch<'a, N, E, Self> where Self: Sized {
+        let mut stack = Vec::new();
         
-        while heap.minimum().is_some() {
-            let min_id: N = heap.extract_min().unwrap().1;
-            
-            match metadata.get_mut(&min_id) {
-                Some(ref mut x) => x.visited = true,
-                None => return Err("There was an error retrieving metadata about a node.".to_string())
-            }
-            
-            try!(perform_edge_relaxation(self, &mut metadata, &mut heap, &min_id));
+        if self.is_node_in_graph(start) {
+            stack.push(TraversalStep::new(start.clone(), 0, None));
         }
         
-        let mut result: HashMap<N, GraphPath<N>> = HashMap::new();
-        for id in &nodes {
-            result.insert(id.clone(), 
-                match backtrack_vertex_predecessor(&metadata, source, id) {
-                    Ok(x) => x,
-                    Err(_) => GraphPath::new()
-            });
+        DepthFirstSearch {
+            graph: self,
+            stack: stack,
+            discovered: HashSet::new(),
+            edge: PhantomData
         }
-        
-        Ok(result)
     }
-
-    /// Finds the diameter of the graph.
+    
+    /// Performs a depth first search of the graph starting at a node, reporting each `DfsEvent` to the visitor.
     ///
-    /// Returns a `GraphPath` of the path that determined the diameter of the graph.
+    /// Edges are classified as tree, back, forward or cross edges. For undirected edges the edge back to a node's parent
+    /// is not reported, and every other edge is reported once as either a tree edge or a back edge.
     ///
-    /// This uses the dijkstras_shortest_paths function to get all shortest paths pairs and find the longest.
-    /// This algorithm runs in O(V<sup>3</sup>) time.
-    fn diameter_path(& self) -> Result<GraphPath<N>, String> where Self: Sized {
-        let nodes: Vec<N> = self.get_nodes();
-        let mut longest_path: GraphPath<N> = GraphPath::new();
-        let mut longest_distance = i32::MIN;
-
-        for id in &nodes {
-            let longest_paths = try!(self.dijkstras_shortest_paths(id));
-            for path in longest_paths.values() {
-                if path.get_distance() > longest_distance {
-                    longest_path = path.clone();
-                    longest_distance = path.get_distance();
-                }
-            }
+    /// This algorithm runs in O(V + E) time.
+    fn dfs_visit<F>(& self, start: &N, mut visitor: F) -> Result<(), GraphError<N>> where Self: Sized, F: FnMut(DfsEvent<N>) {
+        if !self.is_node_in_graph(start) {
+            return Err(GraphError::NodeNotFound(start.clone()));
         }
         
-        Ok(longest_path)
+        let mut state = DfsState::new();
+        depth_first_visit(self, start, &mut state, &mut visitor);
+        
+        Ok(())
     }
 
-    /// Finds the k core of each vertex in the graph.
+    /// Finds the connected components of the graph using breadth first search.
     ///
-    /// Returns a `HashMap` with the core as a key and a vector of all vertex IDs in that core as a value.
+    /// Returns a `ConnectedComponents` that labels each node with the ID of its component and lists the members of each
+    /// component. The direction of edges is ignored, so for directed graphs the weakly connected components are found.
     ///
-    /// This algorithm runs in O(E) time.
-    fn k_core_decomposition(& self) -> HashMap<u32, Vec<N>> where Self: Sized {
-        let mut buckets: HashMap<i32, HashSet<N>> = HashMap::new();
-        let mut metadata: HashMap<N, MetadataKCore<N>> = HashMap::new();
-        let mut result: HashMap<u32, Vec<N>> = HashMap::new();
-        let mut max_degree: i32 = 0;
-        let mut current_core: i32 = 0;
+    /// This algorithm runs in O(V + E) time.
+    fn connected_components(& self) -> ConnectedComponents<N> where Self: Sized {
+        let mut adjacency: HashMap<&N, Vec<&N>> = HashMap::new();
+        let mut result: ConnectedComponents<N> = ConnectedComponents::new();
         
-        for v in &self.get_nodes() {
-            let degree = match self.degree(v) {
-                Ok(d) => d,
-                Err(_) => 0
-            } as i32;
-            
-            if !buckets.contains_key(&degree) {
-                buckets.insert(degree, HashSet::new());
+        for id in self.nodes() {
+            adjacency.insert(id, Vec::new());
+        }
+        
+        for e in self.edges() {
+            match adjacency.get_mut(e.source()) {
+                Some(x) => x.push(e.target()),
+                None => ()
             }
             
-            buckets.get_mut(&degree).unwrap().insert(v.clone());
-            
-            metadata.insert(v.clone(), MetadataKCore {
-                id: v.clone(),
-                degree: degree as u32,
-                core: 0
-            });
-            
-            if degree > max_degree { max_degree = degree };            
+            match adjacency.get_mut(e.target()) {
+                Some(x) => x.push(e.source()),
+                None => ()
+            }
         }
         
-        loop {
-            let mut v: N;
-            
-            match get_next_vertex(&mut buckets, &mut current_core) {
-                Ok(vertex) => v = vertex,
-                Err(_) => if current_core-1 == max_degree { break; } else { continue; }
+        for id in self.nodes() {
+            if result.labels.contains_key(id) {
+                continue;
             }
             
-            let mut v_vertex_meta = metadata.get(&v).cloned().unwrap();
-            v_vertex_meta.core = v_vertex_meta.degree;
+            let component = result.components.len();
+            let mut members: Vec<N> = Vec::new();
+            let mut queue: VecDeque<&N> = VecDeque::new();
             
-            if !result.contains_key(&v_vertex_meta.core) {
-                result.insert( v_vertex_meta.core, Vec::new());
-            }
+            result.labels.insert(id.clone(), component);
+            queue.push_back(id);
             
-            match result.get_mut(&v_vertex_meta.core) {
-                None => (),
-                Some(x) => x.push(v.clone())
+            while let Some(u) = queue.pop_front() {
+                members.push(u.clone());
+                
+                for v in &adjacency[u] {
+                    if !result.labels.contains_key(*v) {
+                        result.labels.insert((*v).clone(), component);
+                        queue.push_back(*v);
+                    }
+                }
             }
             
-            let v_degree = v_vertex_meta.degree;
-
-            for u in &self.get_node_neighbours(&v) {
-                let mut u_vertex_meta = metadata.get(u).cloned().unwrap();
-                
-                if u_vertex_meta.degree > v_degree {
-                    {
-                        match buckets.get_mut(&(u_vertex_meta.degree as i32)) {
-                            Some(bucket) => { bucket.remove(u); },
-                            None => { continue; }
+            result.components.push(members);
+        }
+        
+        result
+    }
+    
+    /// Checks if the graph is connected, ignoring the direction of edges.
+    ///
+    /// A graph with no nodes is considered connected.
+    fn is_connected(& self) -> bool where Self: Sized {
+        self.connected_components().count() <= 1
+    }
+    
+    /// Finds the strongly connected components of the graph using Tarjan's algorithm.
+    ///
+    /// The components are numbered in reverse topological order, so an edge between two components always leads from the
+    /// component with the higher ID to the one with the lower ID. In an undirected graph these are the connected
+    /// components.
+    ///
+    /// This algorithm runs in O(V + E) time.
+    fn strongly_connected_components(& self) -> ConnectedComponents<N> where Self: Sized {
+        let mut state: DfsState<N> = DfsState::new();
+        let mut result: ConnectedComponents<N> = ConnectedComponents::new();
+        let mut indices: HashMap<N, usize> = HashMap::new();
+        let mut low_links: HashMap<N, usize> = HashMap::new();
+        let mut parents: HashMap<N, N> = HashMap::new();
+        let mut stack: Vec<N> = Vec::new();
+        let mut on_stack: HashSet<N> = HashSet::new();
+        
+        {
+            let mut visitor = |event: DfsEvent<N>| {
+                match event {
+                    DfsEvent::Discover(u) => {
+                        let index = indices.len();
+                        indices.insert(u.clone(), index);
+                        low_links.insert(u.clone(), index);
+                        stack.push(u.clone());
+                        on_stack.insert(u);
+                    },
+                    DfsEvent::TreeEdge(u, v) => { parents.insert(v, u); },
+                    DfsEvent::BackEdge(u, v) | DfsEvent::ForwardEdge(u, v) | DfsEvent::CrossEdge(u, v) => {
+                        if on_stack.contains(&v) && indices[&v] < low_links[&u] {
+                            low_links.insert(u, indices[&v]);
                         }
-                    }
-                    
-                    u_vertex_meta.degree = u_vertex_meta.degree-1;
-                    
-                    {
-                        if buckets.contains_key(&(u_vertex_meta.degree as i32)) {
-                            buckets.get_mut(&(u_vertex_meta.degree as i32)).unwrap().insert(u.clone());
-                        } else {
-                            let mut hashset = HashSet::new();
-                            hashset.insert(u.clone());
-                            buckets.insert(u_vertex_meta.degree as i32, hashset);
+                    },
+                    DfsEvent::Finish(u) => {
+                        let low_link = low_links[&u];
+                        
+                        if low_link == indices[&u] {
+                            let component = result.components.len();
+                            let mut members: Vec<N> = Vec::new();
+                            
+                            loop {
+                                let v = stack.pop().unwrap();
+                                on_stack.remove(&v);
+                                result.labels.insert(v.clone(), component);
+                                members.push(v.clone());
+                                
+                                if v == u {
+                                    break;
+                                }
+                            }
+                            
+                            result.components.push(members);
+                        }
+                        
+                        match parents.get(&u) {
+                            Some(p) => {
+                                if low_link < low_links[p] {
+                                    low_links.insert(p.clone(), low_link);
+                                }
+                            },
+                            None => ()
                         }
                     }
-                    
-                    metadata.insert(u.clone(), u_vertex_meta);
                 }
+            };
+            
+            for id in self.nodes() {
+                depth_first_visit(self, id, &mut state, &mut visitor);
             }
         }
         
         result
     }
-
-    /// Creates a minimum spanning tree of the graph using Kruskal's algorithm.
+    
+    /// Sorts the nodes of the graph topologically, so that every edge leads from a node to one later in the order.
+    ///
+    /// Returns `GraphError::Cycle` with the nodes of one cycle if the graph is not acyclic. An undirected graph can only be
+    /// sorted if it is a forest.
+    ///
+    /// This algorithm runs in O(V + E) time.
+    fn topological_sort(& self) -> Result<Vec<N>, GraphError<N>> where Self: Sized {
+        let (cycle, mut order) = perform_cycle_search(self);
+        
+        match cycle {
+            Some(x) => Err(GraphError::Cycle(x)),
+            None => {
+                order.reverse();
+                Ok(order)
+            }
+        }
+    }
+    
+    /// Checks if the graph contains a cycle.
+    ///
+    /// In an undirected graph the edge back to the parent of a node is not counted as a cycle, but a second edge between
+    /// the same pair of nodes is.
+    fn has_cycle(& self) -> bool where Self: Sized {
+        self.find_cycle().is_some()
+    }
+    
+    /// Finds a cycle in the graph, returning its nodes in the order they are visited with the first node not repeated at
+    /// the end, or `None` if the graph is acyclic.
+    ///
+    /// In an undirected graph the edge back to the parent of a node is not counted as a cycle, but a second edge between
+    /// the same pair of nodes is.
+    fn find_cycle(& self) -> Option<Vec<N>> where Self: Sized {
+        perform_cycle_search(self).0
+    }
+    
+    /// Finds the articulation points of an undirected graph, which are the nodes whose removal disconnects their component.
+    ///
+    /// This algorithm runs in O(V + E) time, using the low-link values of a depth first search.
+    fn articulation_points(& self) -> Vec<N> where Self: Sized {
+        perform_biconnected_search(self).0
+    }
+    
+    /// Finds the bridges of an undirected graph, which are the edges whose removal disconnects their component.
+    ///
+    /// This algorithm runs in O(V + E) time, using the low-link values of a depth first search.
+    fn bridges(& self) -> Vec<E> where Self: Sized {
+        let mut edges = PairEdges::new(self);
+        
+        perform_biconnected_search(self).1.iter()
+            .filter_map(|&(ref u, ref v)| edges.take(u, v))
+            .collect()
+    }
+    
+    /// Finds the biconnected components of an undirected graph, returning the edges of each component.
     ///
-    /// Returns the minimum spanning tree in the same graph implementation that is used.
+    /// Every edge apart from self loops belongs to exactly one component, and two components share at most one node, which
+    /// is an articulation point. A bridge forms a component of its own.
     ///
-    /// This algorithm runs in O(Eα(V)) time, where α is the extremely slowly growing inverse of the single-valued Ackermann function.
-    fn kruskal_min_spanning_tree(& self) -> Self where Self: Sized {
-        let mut node_set: DisjointSet<N> = DisjointSet::new();
-        let mut mst_edges = Vec::new();
-        let mut buckets: HashMap<i32, HashSet<E>> = HashMap::new();
+    /// This algorithm runs in O(V + E) time, using the low-link values of a depth first search.
+    fn biconnected_components(& self) -> Vec<Vec<E>> where Self: Sized {
+        let mut edges = PairEdges::new(self);
         
-        let mut current_bucket: i32 = 0;
-        let mut max_bucket: i32 = i32::MIN;
-        let n = self.get_nodes().len()-1;
+        perform_biconnected_search(self).2.iter()
+            .map(|component| component.iter().filter_map(|&(ref u, ref v)| edges.take(u, v)).collect())
+            .collect()
+    }
+    
+    /// Finds an Eulerian circuit of the graph using Hierholzer's algorithm, which is a closed walk that uses every edge
+    /// exactly once.
+    ///
+    /// Returns the nodes of the circuit in order, starting and ending at the same node, or no nodes if the graph has no
+    /// edges. Every node of an undirected graph must have an even degree, and every node of a directed graph must have as
+    /// many edges entering it as leaving it, otherwise `GraphError::NotEulerian` is returned with the nodes that break
+    /// this. Returns `GraphError::Unreachable` if the edges do not all belong to a single component.
+    ///
+    /// This algorithm runs in O(V + E) time.
+    fn eulerian_circuit(& self) -> Result<Vec<N>, GraphError<N>> where Self: Sized {
+        let directed = self.edges().any(|e| e.is_directed());
+        let mut unbalanced: Vec<N> = Vec::new();
         
-        for e in &self.get_edges() {
-            let weight = e.get_weight();
+        for id in self.nodes() {
+            let balanced = if directed {
+                self.neighbours(id).count() == self.predecessors(id).count()
+            } else {
+                try!(self.degree(id)) % 2 == 0
+            };
             
-            if !buckets.contains_key(&weight) {
-                buckets.insert(weight, HashSet::new());
+            if !balanced {
+                unbalanced.push(id.clone());
             }
-            
-            buckets.get_mut(&weight).unwrap().insert(e.clone());
-            
-            if weight > max_bucket { max_bucket = weight };            
         }
         
-        for n in &self.get_nodes() {
-            node_set.make_set(n.clone());
+        if !unbalanced.is_empty() {
+            return Err(GraphError::NotEulerian(unbalanced));
         }
         
-        loop {
-            if mst_edges.len() == n {
-                break;
+        match self.edges().next() {
+            Some(e) => find_eulerian_trail(self, e.source(), directed),
+            None => Ok(Vec::new())
+        }
+    }
+    
+    /// Finds an Eulerian path of the graph using Hierholzer's algorithm, which is a walk that uses every edge exactly
+    /// once.
+    ///
+    /// Returns the nodes of the path in order, or no nodes if the graph has no edges. An undirected graph must have no
+    /// nodes or exactly two nodes of odd degree, at which the path starts and ends. In a directed graph at most one node
+    /// may have one more edge leaving it than entering it, where the path starts, and at most one node the reverse, where
+    /// it ends. Otherwise `GraphError::NotEulerian` is returned with the nodes that break this. Returns
+    /// `GraphError::Unreachable` if the edges do not all belong to a single component.
+    ///
+    /// This algorithm runs in O(V + E) time.
+    fn eulerian_path(& self) -> Result<Vec<N>, GraphError<N>> where Self: Sized {
+        let directed = self.edges().any(|e| e.is_directed());
+        let mut starts: Vec<N> = Vec::new();
+        let mut ends: Vec<N> = Vec::new();
+        let mut unbalanced: Vec<N> = Vec::new();
+        
+        for id in self.nodes() {
+            if directed {
+                let out_degree = self.neighbours(id).count() as i64;
+                let in_degree = self.predecessors(id).count() as i64;
+                
+                if out_degree - in_degree == 1 {
+                    starts.push(id.clone());
+                } else if in_degree - out_degree == 1 {
+                    ends.push(id.clone());
+                } else if in_degree != out_degree {
+                    unbalanced.push(id.clone());
+                }
+            } else if try!(self.degree(id)) % 2 == 1 {
+                starts.push(id.clone());
             }
+        }
+        
+        let valid = if directed {
+            unbalanced.is_empty() && starts.len() == ends.len() && starts.len() <= 1
+        } else {
+            starts.len() == 0 || starts.len() == 2
+        };
+        
+        if !valid {
+            unbalanced.extend(starts);
+            unbalanced.extend(ends);
+            return Err(GraphError::NotEulerian(unbalanced));
+        }
+        
+        match (starts.first(), self.edges().next()) {
+            (Some(start), _) => find_eulerian_trail(self, start, directed),
+            (None, Some(e)) => find_eulerian_trail(self, e.source(), directed),
+            (None, None) => Ok(Vec::new())
+        }
+    }
+    
+    /// Creates the condensation of the graph, in which each strongly connected component is contracted to a single node.
+    ///
+    /// The nodes of the returned graph are the component IDs given by `strongly_connected_components`, and there is an
+    /// edge from one component to another whenever the graph has an edge between their members, weighted by the lightest
+    /// such edge. The condensation of a directed graph is acyclic.
+    fn condensation<G, CE>(& self) -> G where Self: Sized, G: Graph<usize, CE>, CE: Eq + Clone + Hash + Edge<usize, Weight=E::Weight> {
+        let components = self.strongly_connected_components();
+        let mut weights: HashMap<(usize, usize), E::Weight> = HashMap::new();
+        let mut order: Vec<(usize, usize)> = Vec::new();
+        let mut result: G = Graph::new();
+        
+        for i in 0..components.count() {
+            result.add_node(i);
+        }
+        
+        for e in self.edges() {
+            let key = (components.labels[e.source()], components.labels[e.target()]);
             
-            let mut e: E;
-            
-            match get_next_vertex(&mut buckets, &mut current_bucket) {
-                Ok(edge) => e = edge,
-                Err(_) => if current_bucket-1 == max_bucket { break; } else { continue; }
+            if key.0 == key.1 {
+                continue;
             }
             
-            if node_set.find(e.get_target()) != node_set.find(e.get_source()) {
-                mst_edges.push(e.clone());
-                node_set.union(e.get_target(), e.get_source());
+            let lighter = match weights.get(&key) {
+                Some(w) => e.get_weight() < *w,
+                None => {
+                    order.push(key);
+                    true
+                }
+            };
+            
+            if lighter {
+                weights.insert(key, e.get_weight());
             }
         }
         
-        let mut mst: Self = Graph::new();
+        for key in order {
+            result.add_edge(key.0, key.1, weights[&key]);
+        }
         
-        for n in &self.get_nodes() {
-            mst.add_node(n.clone());
+        result
+    }
+
+    /// Performs Dijkstra's shortest path algorithm on the graph.
+    ///
+    /// Returns the `GraphPath` between the two vertices and will end prematurely once the path has been found.
+    /// Returns `GraphError::Unreachable` if there is no path between the vertices, and `GraphError::NegativeWeight` if
+    /// the graph has an edge with a negative weight, for which `bellman_ford_shortest_paths` should be used instead.
+    /// 
+    /// This algorithm runs in worst case O(V<sup>2</sup>) time.
+    fn dijkstras_shortest_path(& self, source: &N, destination: &N) -> Result<GraphPath<N, E::Weight>, GraphError<N>> where Self: Sized {
+        self.a_star_shortest_path(source, destination, no_heuristic)
+    }
+    
+    /// Performs the A* search algorithm on the graph, guided by a heuristic that estimates the distance from a node to the
+    /// destination.
+    ///
+    /// Returns the `GraphPath` between the two vertices and will end prematurely once the path has been found.
+    /// Returns `GraphError::Unreachable` if there is no path between the vertices, and `GraphError::NegativeWeight` if
+    /// the graph has an edge with a negative weight.
+    ///
+    /// If the heuristic is admissible, meaning it never overestimates the distance to the destination, the returned path
+    /// is a shortest path. If it is also consistent, meaning `heuristic(u) <= weight(u, v) + heuristic(v)` for every edge,
+    /// each node is expanded at most once and the search never does more work than Dijkstra's algorithm. An admissible
+    /// heuristic that is not consistent still gives a shortest path, but nodes may be expanded again when a shorter path to
+    /// them is found. With a heuristic that is not admissible the returned path may be longer than the shortest path.
+    ///
+    /// A heuristic that always returns zero makes this equivalent to `dijkstras_shortest_path`.
+    fn a_star_shortest_path<H>(& self, source: &N, destination: &N, heuristic: H) -> Result<GraphPath<N, E::Weight>, GraphError<N>> where Self: Sized, H: Fn(&N) -> E::Weight {
+        if !self.is_node_in_graph(source) {
+            return Err(GraphError::NodeNotFound(source.clone()));
         }
         
-        for e in &mst_edges {
-            mst.add_edge(e.get_source(), e.get_target(), e.get_weight());
+        if !self.is_node_in_graph(destination) {
+            return Err(GraphError::NodeNotFound(destination.clone()));
         }
         
-        return mst;
+        try!(check_non_negative_weights(self));
+        
+        let metadata = try!(dijkstra_search(self, source, Some(destination), &heuristic, &all_edges));
+        
+        backtrack_vertex_predecessor(&metadata, source, destination)
     }
-}
-
-
-
-pub trait Edge<N> {
-    fn new(source: N, target: N, weight: i32, directed: bool) -> Self;
-    
-    fn get_weight(&self) -> i32;
-    
-    fn get_source(&self) -> N;
     
-    fn get_target(&self) -> N;
-    
-    fn is_directed(&self) -> bool;
-}
-
+    /// Performs a bidirectional version of Dijkstra's shortest path algorithm on the graph.
+    ///
+    /// One search runs forwards from the source and another backwards from the destination along the edges entering each
+    /// node, taking turns with their own Fibonacci heaps. The searches stop as soon as a node has been visited by both of
+    /// them, at which point the shortest path is the best one seen through any node reached by both searches.
+    ///
+    /// Returns the same `GraphPath` distance as `dijkstras_shortest_path`, although a different path may be returned when
+    /// several shortest paths exist. Returns `GraphError::Unreachable` if there is no path between the vertices, and
+    /// `GraphError::NegativeWeight` if the graph has an edge with a negative weight.
+    fn bidirectional_dijkstra(& self, source: &N, destination: &N) -> Result<GraphPath<N, E::Weight>, GraphError<N>> where Self: Sized {
+        if !self.is_node_in_graph(source) {
+            return Err(GraphError::NodeNotFound(source.clone()));
+        }
+        
+        if !self.is_node_in_graph(destination) {
+            return Err(GraphError::NodeNotFound(destination.clone()));
+        }
+        
+        try!(check_non_negative_weights(self));
+        
+        let mut forward_heap: FibonacciHeap<HeapKey<E::Weight>, N> = FibonacciHeap::new();
+        let mut backward_heap: FibonacciHeap<HeapKey<E::Weight>, N> = FibonacciHeap::new();
+        let mut forward = try!(create_dijkstra_metadata(self.nodes(), &mut forward_heap, source));
+        let mut backward = try!(create_dijkstra_metadata(self.nodes(), &mut backward_heap, destination));
+        let mut best: Option<(E::Weight, N)> = None;
+        let mut forward_done = false;
+        let mut backward_done = false;
+        
+        while !forward_done || !backward_done {
+            if !forward_done {
+                match try!(perform_bidirectional_step(self, &mut forward_heap, &mut forward, &backward, &mut best, false)) {
+                    SearchStep::Continue => (),
+                    SearchStep::Exhausted => forward_done = true,
+                    SearchStep::Met => break
+                }
+            }
+            
+            if !backward_done {
+                match try!(perform_bidirectional_step(self, &mut backward_heap, &mut backward, &forward, &mut best, true)) {
+                    SearchStep::Continue => (),
+                    SearchStep::Exhausted => backward_done = true,
+                    SearchStep::Met => break
+                }
+            }
+        }
+        
+        let meeting = match best {
+            Some((_, x)) => x,
+            None => return Err(GraphError::Unreachable(source.clone(), destination.clone()))
+        };
+        
+        let mut result = try!(backtrack_vertex_predecessor(&forward, source, &meeting));
+        let second_half = try!(backtrack_vertex_predecessor(&backward, destination, &meeting));
+        
+        let distance = match result.get_distance().checked_add(&second_half.get_distance()) {
+            Some(x) => x,
+            None => return Err(GraphError::WeightOverflow(source.clone(), destination.clone()))
+        };
+        
+        let mut path = result.get_path().clone();
+        path.extend(second_half.get_path().iter().rev().skip(1).cloned());
+        
+        result.set_distance(distance);
+        result.set_path(path);
+        
+        Ok(result)
+    }
+    
+    /// Finds the widest path between two vertices, which is the path that maximises the smallest edge weight along it.
+    ///
+    /// Uses a variant of Dijkstra's algorithm that always visits the node with the widest path found so far, keeping the
+    /// nodes in a Fibonacci heap ordered from the largest width down. Nodes only enter the heap once they are reached, so
+    /// any weight, including `Weight::min_value()` and negative weights, can be the width of a path.
+    ///
+    /// Returns the `GraphPath` between the two vertices, whose distance is the bottleneck weight of the path. The path from
+    /// a vertex to itself has no edges and a distance of `Weight::max_value()`. Returns `GraphError::Unreachable` if there
+    /// is no path between the vertices.
+    ///
+    /// This algorithm runs in O(E + V log V) time.
+    fn widest_path(& self, source: &N, destination: &N) -> Result<GraphPath<N, E::Weight>, GraphError<N>> where Self: Sized {
+        if !self.is_node_in_graph(source) {
+            return Err(GraphError::NodeNotFound(source.clone()));
+        }
+        
+        if !self.is_node_in_graph(destination) {
+            return Err(GraphError::NodeNotFound(destination.clone()));
+        }
+        
+        let mut metadata: HashMap<N, MetadataDijsktra<N, E::Weight>> = HashMap::new();
+        let mut heap: FibonacciHeap<ReverseHeapKey<E::Weight>, N> = FibonacciHeap::new();
+        
+        for id in self.nodes() {
+            metadata.insert(id.clone(), MetadataDijsktra {
+                predecessor: None,
+                visited: false,
+                distance: if id == source { Some(E::Weight::max_value()) } else { None }
+            });
+        }
+        
+        heap.insert(ReverseHeapKey(E::Weight::max_value()), source.clone());
+        
+        while heap.minimum().is_some() {
+            let max_id: N = heap.extract_min().unwrap().1;
+            
+            let width = match metadata.get_mut(&max_id) {
+                Some(&mut MetadataDijsktra { ref mut visited, distance: Some(distance), .. }) => {
+                    *visited = true;
+                    distance
+                },
+                _ => return Err(GraphError::InternalInconsistency(max_id.clone()))
+            };
+            
+            if max_id == *destination {
+                break;
+            }
+            
+            for (id, edge) in self.neighbours(&max_id) {
+                let id_meta;
+                match metadata.get_mut(id) {
+                    Some(x) => id_meta = x,
+                    None => return Err(GraphError::InternalInconsistency(id.clone()))
+                }
+                
+                let bottleneck = if edge.get_weight() < width { edge.get_weight() } else { width };
+                
+                if id_meta.visited {
+                    continue;
+                }
+                
+                match id_meta.distance {
+                    None => {
+                        id_meta.distance = Some(bottleneck);
+                        id_meta.predecessor = Some(max_id.clone());
+                        heap.insert(ReverseHeapKey(bottleneck), id.clone());
+                    },
+                    Some(x) if x < bottleneck => {
+                        id_meta.distance = Some(bottleneck);
+                        id_meta.predecessor = Some(max_id.clone());
+                        let _ = heap.decrease_key(id.clone(), ReverseHeapKey(bottleneck));
+                    },
+                    _ => ()
+                }
+            }
+        }
+        
+        let width = match metadata[destination].distance {
+            Some(x) => x,
+            None => return Err(GraphError::Unreachable(source.clone(), destination.clone()))
+        };
+        
+        let mut result: GraphPath<N, E::Weight> = GraphPath::new();
+        result.set_distance(width);
+        result.set_path(try!(backtrack_predecessors(&metadata, source, destination)));
+        
+        Ok(result)
+    }
+    
+    /// Performs Yen's algorithm on the graph to find the `k` shortest loopless paths between two vertices.
+    ///
+    /// Each path after the first is found by taking a prefix of a path already found, the root path, and joining it to a
+    /// spur path found with Dijkstra's algorithm from the last node of the root path. The spur path may not use the nodes
+    /// of the root path or the edges that the paths already found take out of it.
+    ///
+    /// Returns the paths sorted by distance, which holds fewer than `k` paths if there are not enough distinct paths, and
+    /// no paths if the destination is unreachable. Returns `GraphError::NegativeWeight` if the graph has an edge with a
+    /// negative weight.
+    ///
+    /// This algorithm runs in O(kV(E + V log V)) time.
+    fn k_shortest_paths(& self, source: &N, destination: &N, k: usize) -> Result<Vec<GraphPath<N, E::Weight>>, GraphError<N>> where Self: Sized {
+        if !self.is_node_in_graph(source) {
+            return Err(GraphError::NodeNotFound(source.clone()));
+        }
+        
+        if !self.is_node_in_graph(destination) {
+            return Err(GraphError::NodeNotFound(destination.clone()));
+        }
+        
+        try!(check_non_negative_weights(self));
+        
+        let mut result: Vec<GraphPath<N, E::Weight>> = Vec::new();
+        let mut candidates: Vec<GraphPath<N, E::Weight>> = Vec::new();
+        
+        if k == 0 {
+            return Ok(result);
+        }
+        
+        match self.dijkstras_shortest_path(source, destination) {
+            Ok(x) => result.push(x),
+            Err(GraphError::Unreachable(_, _)) => return Ok(result),
+            Err(e) => return Err(e)
+        }
+        
+        while result.len() < k {
+            let previous = result[result.len() - 1].get_path().clone();
+            let mut root_distance: E::Weight = Weight::zero();
+            
+            for i in 0..(previous.len() - 1) {
+                let spur = &previous[i];
+                let root = &previous[..i + 1];
+                
+                if i > 0 {
+                    let weight = try!(get_path_edge_weight(self, &previous[i - 1], spur));
+                    root_distance = match root_distance.checked_add(&weight) {
+                        Some(x) => x,
+                        None => return Err(GraphError::WeightOverflow(previous[i - 1].clone(), spur.clone()))
+                    };
+                }
+                
+                let mut blocked_edges: HashSet<(N, N)> = HashSet::new();
+                for path in result.iter() {
+                    let nodes = path.get_path();
+                    if nodes.len() > i + 1 && &nodes[..i + 1] == root {
+                        blocked_edges.insert((nodes[i].clone(), nodes[i + 1].clone()));
+                    }
+                }
+                
+                let blocked_nodes: HashSet<N> = root[..i].iter().cloned().collect();
+                let allowed = |from: &N, to: &N| !blocked_nodes.contains(to) && !blocked_edges.contains(&(from.clone(), to.clone()));
+                
+                let metadata = try!(dijkstra_search(self, spur, Some(destination), &no_heuristic, &allowed));
+                let spur_path = match backtrack_vertex_predecessor(&metadata, spur, destination) {
+                    Ok(x) => x,
+                    Err(GraphError::Unreachable(_, _)) => continue,
+                    Err(e) => return Err(e)
+                };
+                
+                let mut candidate: GraphPath<N, E::Weight> = GraphPath::new();
+                let mut path: Vec<N> = root[..i].to_vec();
+                path.extend(spur_path.get_path().iter().cloned());
+                
+                match root_distance.checked_add(&spur_path.get_distance()) {
+                    Some(x) => candidate.set_distance(x),
+                    None => return Err(GraphError::WeightOverflow(source.clone(), destination.clone()))
+                }
+                
+                if !candidates.iter().any(|x| *x.get_path() == path) {
+                    candidate.set_path(path);
+                    candidates.push(candidate);
+                }
+            }
+            
+            if candidates.is_empty() {
+                break;
+            }
+            
+            let mut shortest = 0;
+            for (i, x) in candidates.iter().enumerate() {
+                if compare_weights(&x.get_distance(), &candidates[shortest].get_distance()) == Ordering::Less {
+                    shortest = i;
+                }
+            }
+            
+            result.push(candidates.remove(shortest));
+        }
+        
+        Ok(result)
+    }
+    
+    /// Performs Dijkstra's shortest path algorithm on the graph.
+    ///
+    /// Returns a `HashMap` of target vertices to the `GraphPath` between the vertex and the target vertex.
+    /// Unreachable vertices are mapped to an empty `GraphPath`.
+    /// Returns `GraphError::NegativeWeight` if the graph has an edge with a negative weight.
+    /// 
+    /// This algorithm runs in worst case O(V<sup>2</sup>) time.
+    fn dijkstras_shortest_paths(& self, source: &N) -> Result<HashMap<N, GraphPath<N, E::Weight>>, GraphError<N>> where Self: Sized {
+        if !self.is_node_in_graph(source) {
+            return Err(GraphError::NodeNotFound(source.clone()));
+        }
+        
+        try!(check_non_negative_weights(self));
+        
+        let metadata = try!(dijkstra_search(self, source, None, &no_heuristic, &all_edges));
+        
+        let mut result: HashMap<N, GraphPath<N, E::Weight>> = HashMap::new();
+        for id in self.nodes() {
+            result.insert(id.clone(), 
+                match backtrack_vertex_predecessor(&metadata, source, id) {
+                    Ok(x) => x,
+                    Err(_) => GraphPath::new()
+            });
+        }
+        
+        Ok(result)
+    }
+
+    /// Performs the Bellman-Ford shortest path algorithm on the graph, which supports negative edge weights.
+    ///
+    /// Returns a `HashMap` of target vertices to the `GraphPath` between the vertex and the target vertex.
+    /// Unreachable vertices are mapped to an empty `GraphPath`.
+    /// Returns `GraphError::NegativeCycle` with the nodes of the cycle if a negative weight cycle is reachable from the
+    /// source. In an undirected graph any edge with a negative weight forms such a cycle.
+    ///
+    /// This algorithm runs in O(VE) time.
+    fn bellman_ford_shortest_paths(& self, source: &N) -> Result<HashMap<N, GraphPath<N, E::Weight>>, GraphError<N>> where Self: Sized {
+        if !self.is_node_in_graph(source) {
+            return Err(GraphError::NodeNotFound(source.clone()));
+        }
+        
+        let mut metadata: HashMap<N, MetadataDijsktra<N, E::Weight>> = HashMap::new();
+        
+        for id in self.nodes() {
+            metadata.insert(id.clone(), MetadataDijsktra {
+                predecessor: None,
+                visited: false,
+                distance: if id == source { Some(Weight::zero()) } else { None }
+            });
+        }
+        
+        try!(perform_bellman_ford(self, &mut metadata));
+        
+        let mut result: HashMap<N, GraphPath<N, E::Weight>> = HashMap::new();
+        for id in self.nodes() {
+            result.insert(id.clone(), 
+                match backtrack_vertex_predecessor(&metadata, source, id) {
+                    Ok(x) => x,
+                    Err(_) => GraphPath::new()
+            });
+        }
+        
+        Ok(result)
+    }
+
+    /// Performs Johnson's all pairs shortest path algorithm on the graph, which supports negative edge weights.
+    ///
+    /// Potentials for every node are found with the Bellman-Ford algorithm, which makes every edge weight non-negative
+    /// once reweighted as `weight(u, v) + potential(u) - potential(v)`. Dijkstra's algorithm is then run from every node.
+    /// The reweighting is applied through the heap keys rather than by copying the graph, so the distances in the returned
+    /// paths are in the original weights.
+    ///
+    /// Returns a `HashMap` of source vertices to the `HashMap` of target vertices to the `GraphPath` between them.
+    /// Unreachable vertices are mapped to an empty `GraphPath`.
+    /// Returns `GraphError::NegativeCycle` if the graph contains a negative weight cycle.
+    ///
+    /// This algorithm runs in O(VE + V(E + V log V)) time.
+    fn johnson_shortest_paths(& self) -> Result<HashMap<N, HashMap<N, GraphPath<N, E::Weight>>>, GraphError<N>> where Self: Sized {
+        let mut metadata: HashMap<N, MetadataDijsktra<N, E::Weight>> = HashMap::new();
+        let mut offsets: HashMap<N, E::Weight> = HashMap::new();
+        let zero: E::Weight = Weight::zero();
+        
+        for id in self.nodes() {
+            metadata.insert(id.clone(), MetadataDijsktra {
+                predecessor: None,
+                visited: false,
+                distance: Some(zero)
+            });
+        }
+        
+        try!(perform_bellman_ford(self, &mut metadata));
+        
+        for (id, x) in metadata.iter() {
+            match x.distance.and_then(|d| zero.checked_sub(&d)) {
+                Some(offset) => { offsets.insert(id.clone(), offset); },
+                None => return Err(GraphError::WeightOverflow(id.clone(), id.clone()))
+            }
+        }
+        
+        let heuristic = |id: &N| offsets[id];
+        let mut result: HashMap<N, HashMap<N, GraphPath<N, E::Weight>>> = HashMap::new();
+        
+        for source in self.nodes() {
+            let metadata = try!(dijkstra_search(self, source, None, &heuristic, &all_edges));
+            let mut paths: HashMap<N, GraphPath<N, E::Weight>> = HashMap::new();
+            
+            for id in self.nodes() {
+                paths.insert(id.clone(), 
+                    match backtrack_vertex_predecessor(&metadata, source, id) {
+                        Ok(x) => x,
+                        Err(_) => GraphPath::new()
+                });
+            }
+            
+            result.insert(source.clone(), paths);
+        }
+        
+        Ok(result)
+    }
+
+    /// Performs the Floyd-Warshall all pairs shortest path algorithm on the graph, which supports negative edge weights.
+    ///
+    /// Returns a `DistanceMatrix` holding the distance and next hop between every pair of nodes, from which the `GraphPath`
+    /// between any two nodes can be reconstructed.
+    /// Returns `GraphError::NegativeCycle` if the graph contains a negative weight cycle.
+    ///
+    /// This algorithm runs in O(V<sup>3</sup>) time and uses O(V<sup>2</sup>) space.
+    fn floyd_warshall(& self) -> Result<DistanceMatrix<N, E::Weight>, GraphError<N>> where Self: Sized {
+        let mut matrix: DistanceMatrix<N, E::Weight> = DistanceMatrix::new(self.get_nodes());
+        let n = matrix.nodes.len();
+        
+        for i in 0..n {
+            for (id, edge) in self.neighbours(&matrix.nodes[i]) {
+                let j = matrix.indices[id];
+                let weight = edge.get_weight();
+                let is_shorter = match matrix.distances[i * n + j] {
+                    Some(distance) => weight < distance,
+                    None => true
+                };
+                
+                if is_shorter {
+                    matrix.distances[i * n + j] = Some(weight);
+                    matrix.next[i * n + j] = Some(j);
+                }
+            }
+            
+            // Only a self loop with a negative weight can lower the diagonal before any node is used as an intermediate.
+            if matrix.distances[i * n + i].unwrap() < Weight::zero() {
+                return Err(GraphError::NegativeCycle(vec![matrix.nodes[i].clone()]));
+            }
+        }
+        
+        for k in 0..n {
+            for i in 0..n {
+                let to_k = match matrix.distances[i * n + k] {
+                    Some(distance) => distance,
+                    None => continue
+                };
+                
+                for j in 0..n {
+                    let from_k = match matrix.distances[k * n + j] {
+                        Some(distance) => distance,
+                        None => continue
+                    };
+                    
+                    let length = match to_k.checked_add(&from_k) {
+                        Some(x) => x,
+                        None => return Err(GraphError::WeightOverflow(matrix.nodes[i].clone(), matrix.nodes[j].clone()))
+                    };
+                    
+                    let is_shorter = match matrix.distances[i * n + j] {
+                        Some(distance) => length < distance,
+                        None => true
+                    };
+                    
+                    if is_shorter {
+                        matrix.distances[i * n + j] = Some(length);
+                        matrix.next[i * n + j] = matrix.next[i * n + k];
+                    }
+                }
+                
+                if matrix.distances[i * n + i].unwrap() < Weight::zero() {
+                    return Err(GraphError::NegativeCycle(try!(find_matrix_cycle(self, &matrix, i, k))));
+                }
+            }
+        }
+        
+        Ok(matrix)
+    }
+    
+    /// Finds the shortest paths between all pairs of nodes by running Dijkstra's algorithm from every node.
+    ///
+    /// Returns the same `DistanceMatrix` as `floyd_warshall`, but is faster on sparse graphs. The row of each source is
+    /// filled in straight from the predecessors found by its search, so no paths are built along the way.
+    /// Returns `GraphError::NegativeWeight` if the graph has an edge with a negative weight.
+    ///
+    /// This algorithm runs in O(V(E + V log V)) time.
+    fn dijkstras_all_pairs_shortest_paths(& self) -> Result<DistanceMatrix<N, E::Weight>, GraphError<N>> where Self: Sized {
+        try!(check_non_negative_weights(self));
+        
+        let mut matrix: DistanceMatrix<N, E::Weight> = DistanceMatrix::new(self.get_nodes());
+        let n = matrix.nodes.len();
+        
+        for i in 0..n {
+            let metadata = try!(dijkstra_search(self, &matrix.nodes[i], None, &no_heuristic, &all_edges));
+            
+            for j in 0..n {
+                if matrix.next[i * n + j].is_some() {
+                    continue;
+                }
+                
+                // Walk back along the predecessors until the first hop is known, then share it with every node walked.
+                let mut walked: Vec<usize> = Vec::new();
+                let mut current = j;
+                let next_hop;
+                
+                loop {
+                    let predecessor = match metadata.get(&matrix.nodes[current]) {
+                        Some(&MetadataDijsktra { distance: None, .. }) => {
+                            next_hop = None;
+                            break;
+                        },
+                        Some(x) => {
+                            matrix.distances[i * n + current] = x.distance;
+                            walked.push(current);
+                            
+                            match x.predecessor {
+                                Some(ref y) => matrix.indices[y],
+                                None => return Err(GraphError::InternalInconsistency(matrix.nodes[current].clone()))
+                            }
+                        },
+                        None => return Err(GraphError::InternalInconsistency(matrix.nodes[current].clone()))
+                    };
+                    
+                    if predecessor == i {
+                        next_hop = Some(current);
+                        break;
+                    }
+                    
+                    match matrix.next[i * n + predecessor] {
+                        Some(x) => {
+                            next_hop = Some(x);
+                            break;
+                        },
+                        None => current = predecessor
+                    }
+                }
+                
+                for k in walked {
+                    matrix.next[i * n + k] = next_hop;
+                }
+            }
+        }
+        
+        Ok(matrix)
+    }
+
+    /// Finds the diameter of the graph.
+    ///
+    /// Returns a `GraphPath` of the path that determined the diameter of the graph.
+    ///
+    /// This uses the dijkstras_shortest_paths function to get all shortest paths pairs and find the longest.
+    /// Pairs of nodes with no path between them are ignored, so `is_connected` should be checked first when that matters.
+    /// This algorithm runs in O(V<sup>3</sup>) time.
+    fn diameter_path(& self) -> Result<GraphPath<N, E::Weight>, GraphError<N>> where Self: Sized {
+        let mut longest_path: Option<GraphPath<N, E::Weight>> = None;
+
+        for id in self.nodes() {
+            let longest_paths = try!(self.dijkstras_shortest_paths(id));
+            for path in longest_paths.values() {
+                let is_longer = match longest_path {
+                    Some(ref longest) => path.get_distance() > longest.get_distance(),
+                    None => true
+                };
+                
+                if is_longer {
+                    longest_path = Some(path.clone());
+                }
+            }
+        }
+        
+        Ok(longest_path.unwrap_or(GraphPath::new()))
+    }
+
+    /// Finds the k core of each vertex in the graph.
+    ///
+    /// Returns a `HashMap` with the core as a key and a vector of all vertex IDs in that core as a value.
+    ///
+    /// This algorithm runs in O(E) time.
+    fn k_core_decomposition(& self) -> HashMap<u32, Vec<N>> where Self: Sized {
+        let mut buckets: HashMap<i32, HashSet<N>> = HashMap::new();
+        let mut metadata: HashMap<N, MetadataKCore<N>> = HashMap::new();
+        let mut result: HashMap<u32, Vec<N>> = HashMap::new();
+        let mut max_degree: i32 = 0;
+        let mut current_core: i32 = 0;
+        
+        for v in self.nodes() {
+            let degree = match self.degree(v) {
+                Ok(d) => d,
+                Err(_) => 0
+            } as i32;
+            
+            if !buckets.contains_key(&degree) {
+                buckets.insert(degree, HashSet::new());
+            }
+            
+            buckets.get_mut(&degree).unwrap().insert(v.clone());
+            
+            metadata.insert(v.clone(), MetadataKCore {
+                id: v.clone(),
+                degree: degree as u32,
+                core: 0
+            });
+            
+            if degree > max_degree { max_degree = degree };            
+        }
+        
+        loop {
+            let mut v: N;
+            
+            match get_next_vertex(&mut buckets, &mut current_core) {
+                Ok(vertex) => v = vertex,
+                Err(_) => if current_core-1 == max_degree { break; } else { continue; }
+            }
+            
+            let mut v_vertex_meta = metadata.get(&v).cloned().unwrap();
+            v_vertex_meta.core = v_vertex_meta.degree;
+            
+            if !result.contains_key(&v_vertex_meta.core) {
+                result.insert( v_vertex_meta.core, Vec::new());
+            }
+            
+            match result.get_mut(&v_vertex_meta.core) {
+                None => (),
+                Some(x) => x.push(v.clone())
+            }
+            
+            let v_degree = v_vertex_meta.degree;
+
+            for (u, _) in self.neighbours(&v) {
+                let mut u_vertex_meta = metadata.get(u).cloned().unwrap();
+                
+                if u_vertex_meta.degree > v_degree {
+                    {
+                        match buckets.get_mut(&(u_vertex_meta.degree as i32)) {
+                            Some(bucket) => { bucket.remove(u); },
+                            None => { continue; }
+                        }
+                    }
+                    
+                    u_vertex_meta.degree = u_vertex_meta.degree-1;
+                    
+                    {
+                        if buckets.contains_key(&(u_vertex_meta.degree as i32)) {
+                            buckets.get_mut(&(u_vertex_meta.degree as i32)).unwrap().insert(u.clone());
+                        } else {
+                            let mut hashset = HashSet::new();
+                            hashset.insert(u.clone());
+                            buckets.insert(u_vertex_meta.degree as i32, hashset);
+                        }
+                    }
+                    
+                    metadata.insert(u.clone(), u_vertex_meta);
+                }
+            }
+        }
+        
+        result
+    }
+
+    /// Creates a minimum spanning tree of the graph using Kruskal's algorithm.
+    ///
+    /// Returns the minimum spanning tree in the same graph implementation that is used, with the node and edge payloads
+    /// copied across. If the graph is not connected the result is a minimum spanning forest, which
+    /// `min_spanning_forest` splits into one tree per component.
+    ///
+    /// This algorithm runs in O(E log E) time, dominated by sorting the edges by weight.
+    fn kruskal_min_spanning_tree(& self) -> Self where Self: Sized, Self::NodeData: Clone, Self::EdgeData: Clone {
+        let mst_edges = find_kruskal_edges(self, false);
+        
+        create_subgraph(self, self.nodes(), &mst_edges)
+    }
+    
+    /// Creates a maximum spanning tree of the graph using Kruskal's algorithm, taking the heaviest edges first.
+    ///
+    /// Returns the maximum spanning tree in the same graph implementation that is used, with the node and edge payloads
+    /// copied across. If the graph is not connected the result is a maximum spanning forest.
+    ///
+    /// This algorithm runs in O(E log E) time, dominated by sorting the edges by weight.
+    fn max_spanning_tree(& self) -> Self where Self: Sized, Self::NodeData: Clone, Self::EdgeData: Clone {
+        let mst_edges = find_kruskal_edges(self, true);
+        
+        create_subgraph(self, self.nodes(), &mst_edges)
+    }
+    
+    /// Creates a minimum spanning forest of the graph using Kruskal's algorithm, ignoring the direction of edges.
+    ///
+    /// Returns one minimum spanning tree per connected component, in the order of `connected_components`, each paired with
+    /// the total weight of its edges. The trees are in the same graph implementation that is used, with the node and edge
+    /// payloads copied across. An empty graph has no trees and a node without edges forms a tree of weight zero.
+    /// Returns `GraphError::WeightOverflow` if the total weight of a tree overflows.
+    ///
+    /// This algorithm runs in O(E log E) time.
+    fn min_spanning_forest(& self) -> Result<Vec<(Self, E::Weight)>, GraphError<N>> where Self: Sized, Self::NodeData: Clone, Self::EdgeData: Clone {
+        let components = self.connected_components();
+        let mut tree_edges: Vec<Vec<E>> = components.get_components().iter().map(|_| Vec::new()).collect();
+        let mut result = Vec::new();
+        
+        for e in find_kruskal_edges(self, false) {
+            match components.get_component(e.source()) {
+                Some(i) => tree_edges[i].push(e),
+                None => return Err(GraphError::InternalInconsistency(e.get_source()))
+            }
+        }
+        
+        for (members, edges) in components.get_components().iter().zip(tree_edges.iter()) {
+            let mut weight: E::Weight = Weight::zero();
+            
+            for e in edges {
+                weight = match weight.checked_add(&e.get_weight()) {
+                    Some(x) => x,
+                    None => return Err(GraphError::WeightOverflow(e.get_source(), e.get_target()))
+                };
+            }
+            
+            result.push((create_subgraph(self, members.iter(), edges), weight));
+        }
+        
+        Ok(result)
+    }
+    
+    /// Creates a minimum spanning tree of the graph using Prim's algorithm.
+    ///
+    /// Edges are followed in both directions, so directed graphs are treated as undirected as in
+    /// `kruskal_min_spanning_tree`. If the graph is not connected the result is a minimum spanning forest, with one tree
+    /// per component. Returns the tree in the same graph implementation that is used, with the node and edge payloads
+    /// copied across.
+    ///
+    /// This algorithm runs in O(E + V log V) time.
+    fn prim_min_spanning_tree(& self) -> Self where Self: Sized, Self::NodeData: Clone, Self::EdgeData: Clone {
+        let mut metadata: HashMap<N, MetadataPrim<E>> = HashMap::new();
+        let mut heap: FibonacciHeap<HeapKey<E::Weight>, N> = FibonacciHeap::new();
+        let mut mst_edges: Vec<E> = Vec::new();
+        
+        for n in self.nodes() {
+            metadata.insert(n.clone(), MetadataPrim { edge: None, in_tree: false });
+            heap.insert(HeapKey(E::Weight::max_value()), n.clone());
+        }
+        
+        while heap.minimum().is_some() {
+            let min_id: N = heap.extract_min().unwrap().1;
+            
+            match metadata.get_mut(&min_id) {
+                Some(ref mut x) => {
+                    x.in_tree = true;
+                    
+                    match x.edge {
+                        Some(e) => mst_edges.push(e.clone()),
+                        None => ()
+                    }
+                },
+                None => ()
+            }
+            
+            for (id, edge) in self.neighbours(&min_id).chain(self.predecessors(&min_id)) {
+                match metadata.get_mut(id) {
+                    Some(ref mut x) => {
+                        if x.in_tree {
+                            continue;
+                        }
+                        
+                        let lighter = match x.edge {
+                            Some(e) => edge.get_weight() < e.get_weight(),
+                            None => true
+                        };
+                        
+                        if lighter {
+                            x.edge = Some(edge);
+                            let _ = heap.decrease_key(id.clone(), HeapKey(edge.get_weight()));
+                        }
+                    },
+                    None => ()
+                }
+            }
+        }
+        
+        create_subgraph(self, self.nodes(), &mst_edges)
+    }
+    
+    /// Finds the maximum flow from the source to the sink, using the weight of each edge as its capacity.
+    ///
+    /// The flow is found with either the Edmonds-Karp or Dinic algorithm, chosen by `algorithm`. An undirected edge can
+    /// carry flow in either direction up to its capacity. Returns `GraphError::NegativeWeight` if an edge has a negative
+    /// capacity and `GraphError::WeightOverflow` if the flow overflows the weight type. The flow from a node to itself is
+    /// zero.
+    ///
+    /// Edmonds-Karp runs in O(VE<sup>2</sup>) time and Dinic in O(V<sup>2</sup>E) time.
+    fn max_flow(& self, source: &N, sink: &N, algorithm: MaxFlowAlgorithm) -> Result<MaxFlow<E, E::Weight>, GraphError<N>> where Self: Sized {
+        let (network, value) = try!(compute_max_flow(self, source, sink, algorithm));
+        let mut flows: HashMap<E, E::Weight> = HashMap::new();
+        
+        for (i, e) in self.edges().enumerate() {
+            let arc = &network.arcs[2 * i];
+            
+            if arc.residual <= arc.capacity {
+                try!(add_edge_flow(&mut flows, e.clone(), arc.capacity.checked_sub(&arc.residual)));
+            } else {
+                try!(add_edge_flow(&mut flows, Edge::with_cost(e.get_target(), e.get_source(), e.get_weight(), e.get_cost(), false), arc.residual.checked_sub(&arc.capacity)));
+            }
+        }
+        
+        Ok(MaxFlow {
+            value: value,
+            flows: flows
+        })
+    }
+    
+    /// Finds a minimum cut separating the source from the sink, using the weight of each edge as its capacity.
+    ///
+    /// A maximum flow is found with Dinic's algorithm, after which the source side of the cut is every node that can still
+    /// be reached from the source through edges with capacity to spare. The cut edges are the edges leading from the
+    /// source side to the sink side, or for undirected graphs every edge between the two sides, and their total weight
+    /// equals the value of the maximum flow. Returns `GraphError::NegativeWeight` if an edge has a negative capacity.
+    ///
+    /// This algorithm runs in O(V<sup>2</sup>E) time.
+    fn min_cut(& self, source: &N, sink: &N) -> Result<MinCut<N, E, E::Weight>, GraphError<N>> where Self: Sized {
+        let (network, value) = try!(compute_max_flow(self, source, sink, MaxFlowAlgorithm::Dinic));
+        let (levels, _) = network.levels(network.indices[source]);
+        let mut result = MinCut {
+            weight: value,
+            source_side: Vec::new(),
+            sink_side: Vec::new(),
+            edges: Vec::new()
+        };
+        
+        for (i, id) in network.nodes.iter().enumerate() {
+            if levels[i].is_some() {
+                result.source_side.push(id.clone());
+            } else {
+                result.sink_side.push(id.clone());
+            }
+        }
+        
+        for e in self.edges() {
+            let source_reached = levels[network.indices[e.source()]].is_some();
+            let target_reached = levels[network.indices[e.target()]].is_some();
+            
+            if (source_reached && !target_reached) || (!e.is_directed() && !source_reached && target_reached) {
+                result.edges.push(e.clone());
+            }
+        }
+        
+        Ok(result)
+    }
+    
+    /// Finds a minimum cut of the whole graph using the Stoer-Wagner algorithm, without fixing a source and sink.
+    ///
+    /// Edges are treated as undirected, with parallel edges adding their weights together. Each phase of the algorithm grows
+    /// a set of nodes by repeatedly adding the node most tightly connected to it, which is found with a Fibonacci heap,
+    /// and then merges the last two nodes added. The cut between the last node and the rest of the graph is a candidate,
+    /// and the lightest candidate over all phases is a minimum cut. The two sides of the returned cut are both non-empty
+    /// unless the graph has fewer than two nodes, in which case every node is on the source side and the cut has a weight
+    /// of zero. Returns `GraphError::NegativeWeight` if an edge has a negative weight.
+    ///
+    /// This algorithm runs in O(VE + V<sup>2</sup> log V) time.
+    fn global_min_cut(& self) -> Result<MinCut<N, E, E::Weight>, GraphError<N>> where Self: Sized {
+        let nodes: Vec<N> = self.get_nodes();
+        let mut indices: HashMap<&N, usize> = HashMap::new();
+        let mut adjacency: Vec<HashMap<usize, E::Weight>> = nodes.iter().map(|_| HashMap::new()).collect();
+        let mut groups: Vec<Vec<usize>> = (0..nodes.len()).map(|i| vec![i]).collect();
+        let mut active: Vec<usize> = (0..nodes.len()).collect();
+        let mut best: Option<(E::Weight, Vec<usize>)> = None;
+        
+        for (i, id) in nodes.iter().enumerate() {
+            indices.insert(id, i);
+        }
+        
+        for e in self.edges() {
+            if e.get_weight() < Weight::zero() {
+                return Err(GraphError::NegativeWeight(e.get_source(), e.get_target()));
+            }
+            
+            let u = indices[e.source()];
+            let v = indices[e.target()];
+            
+            if u != v {
+                try!(add_merged_weight(&mut adjacency, u, v, e.get_weight(), &nodes));
+                try!(add_merged_weight(&mut adjacency, v, u, e.get_weight(), &nodes));
+            }
+        }
+        
+        while active.len() > 1 {
+            let mut heap: FibonacciHeap<ReverseHeapKey<E::Weight>, usize> = FibonacciHeap::new();
+            let mut connectivity: HashMap<usize, E::Weight> = HashMap::new();
+            let mut added: HashSet<usize> = HashSet::new();
+            let mut previous = active[0];
+            let mut last = active[0];
+            
+            for &u in &active {
+                heap.insert(ReverseHeapKey(Weight::zero()), u);
+                connectivity.insert(u, Weight::zero());
+            }
+            
+            while heap.minimum().is_some() {
+                let u = heap.extract_min().unwrap().1;
+                added.insert(u);
+                previous = last;
+                last = u;
+                
+                for (&v, w) in adjacency[u].iter() {
+                    if added.contains(&v) {
+                        continue;
+                    }
+                    
+                    let total = match connectivity[&v].checked_add(w) {
+                        Some(x) => x,
+                        None => return Err(GraphError::WeightOverflow(nodes[u].clone(), nodes[v].clone()))
+                    };
+                    
+                    connectivity.insert(v, total);
+                    let _ = heap.decrease_key(v, ReverseHeapKey(total));
+                }
+            }
+            
+            let lighter = match best {
+                Some((ref weight, _)) => connectivity[&last] < *weight,
+                None => true
+            };
+            
+            if lighter {
+                best = Some((connectivity[&last], groups[last].clone()));
+            }
+            
+            let merged = ::std::mem::replace(&mut groups[last], Vec::new());
+            groups[previous].extend(merged);
+            
+            let edges: Vec<(usize, E::Weight)> = adjacency[last].drain().collect();
+            for (v, w) in edges {
+                adjacency[v].remove(&last);
+                
+                if v != previous {
+                    try!(add_merged_weight(&mut adjacency, previous, v, w, &nodes));
+                    try!(add_merged_weight(&mut adjacency, v, previous, w, &nodes));
+                }
+            }
+            
+            active.retain(|&u| u != last);
+        }
+        
+        let (weight, sink) = match best {
+            Some(x) => x,
+            None => (Weight::zero(), Vec::new())
+        };
+        let sink: HashSet<usize> = sink.into_iter().collect();
+        let mut result = MinCut {
+            weight: weight,
+            source_side: Vec::new(),
+            sink_side: Vec::new(),
+            edges: Vec::new()
+        };
+        
+        for (i, id) in nodes.iter().enumerate() {
+            if sink.contains(&i) {
+                result.sink_side.push(id.clone());
+            } else {
+                result.source_side.push(id.clone());
+            }
+        }
+        
+        for e in self.edges() {
+            if sink.contains(&indices[e.source()]) != sink.contains(&indices[e.target()]) {
+                result.edges.push(e.clone());
+            }
+        }
+        
+        Ok(result)
+    }
+    
+    /// Finds the maximum flow from the source to the sink with the lowest total cost, using the weight of each edge as its
+    /// capacity and `Edge::get_cost` as the cost of sending one unit of flow along it.
+    ///
+    /// Costs are stored with each edge, so parallel edges can have different costs, and are set with `add_edge_with_cost`.
+    /// Flow is sent along the cheapest path left in the residual network until the sink can no longer be reached, with the
+    /// paths found by Dijkstra's algorithm on costs reweighted by the distances of the previous search so that cancelling
+    /// flow never gives a negative cost. An undirected edge can carry flow in either direction up to its capacity, at the
+    /// same cost. Returns `GraphError::NegativeWeight` if an edge has a negative capacity or cost, and
+    /// `GraphError::WeightOverflow` if the flow or its cost overflows the weight type. The flow from a node to itself is
+    /// zero.
+    ///
+    /// This algorithm runs in O(F(E + V log V)) time, where F is the number of augmenting paths.
+    fn min_cost_max_flow(& self, source: &N, sink: &N) -> Result<MinCostFlow<E, E::Weight>, GraphError<N>> where Self: Sized {
+        if !self.is_node_in_graph(source) {
+            return Err(GraphError::NodeNotFound(source.clone()));
+        }
+        
+        if !self.is_node_in_graph(sink) {
+            return Err(GraphError::NodeNotFound(sink.clone()));
+        }
+        
+        let (mut network, edge_arcs) = try!(ResidualNetwork::with_costs(self));
+        let s = network.indices[source];
+        let t = network.indices[sink];
+        let mut potentials: Vec<E::Weight> = vec![Weight::zero(); network.nodes.len()];
+        let mut value: E::Weight = Weight::zero();
+        let mut total_cost: E::Weight = Weight::zero();
+        
+        if s == t {
+            return Ok(MinCostFlow {
+                cost: total_cost,
+                value: value,
+                flows: HashMap::new()
+            });
+        }
+        
+        loop {
+            let (distances, parents) = try!(find_cheapest_path(&network, &potentials, s));
+            
+            let distance = match distances[t] {
+                Some(x) => x,
+                None => break
+            };
+            
+            let mut path: Vec<usize> = Vec::new();
+            let mut v = t;
+            
+            while v != s {
+                let a = parents[v].unwrap();
+                path.push(a);
+                v = network.arcs[a ^ 1].target;
+            }
+            
+            let amount = network.bottleneck(&path);
+            try!(network.augment(&path, amount));
+            
+            let path_cost = amount.checked_mul(&distance);
+            
+            match (value.checked_add(&amount), path_cost.and_then(|x| total_cost.checked_add(&x))) {
+                (Some(x), Some(y)) => {
+                    value = x;
+                    total_cost = y;
+                },
+                _ => return Err(GraphError::WeightOverflow(source.clone(), sink.clone()))
+            }
+            
+            for (i, d) in distances.into_iter().enumerate() {
+                match d {
+                    Some(x) => potentials[i] = x,
+                    None => ()
+                }
+            }
+        }
+        
+        let mut flows: HashMap<E, E::Weight> = HashMap::new();
+        
+        for (e, &(forward, backward)) in self.edges().zip(edge_arcs.iter()) {
+            let arc = &network.arcs[forward];
+            try!(add_edge_flow(&mut flows, e.clone(), arc.capacity.checked_sub(&arc.residual)));
+            
+            match backward {
+                Some(b) => {
+                    let arc = &network.arcs[b];
+                    if arc.residual < arc.capacity {
+                        try!(add_edge_flow(&mut flows, Edge::with_cost(e.get_target(), e.get_source(), e.get_weight(), e.get_cost(), false), arc.capacity.checked_sub(&arc.residual)));
+                    }
+                },
+                None => ()
+            }
+        }
+        
+        Ok(MinCostFlow {
+            cost: total_cost,
+            value: value,
+            flows: flows
+        })
+    }
+}
+
+
+
+pub trait Edge<N> {
+    type Weight: Weight;
+    
+    fn new(source: N, target: N, weight: Self::Weight, directed: bool) -> Self;
+    
+    /// Creates an edge that also carries the cost of sending one unit of flow along it.
+    ///
+    /// Edge types that do not store a cost ignore it and create the edge with `new`.
+    fn with_cost(source: N, target: N, weight: Self::Weight, cost: Self::Weight, directed: bool) -> Self where Self: Sized {
+        let _ = cost;
+        Edge::new(source, target, weight, directed)
+    }
+    
+    fn get_weight(&self) -> Self::Weight;
+    
+    /// Returns the cost of sending one unit of flow along the edge, which is zero for edge types that do not store one.
+    fn get_cost(&self) -> Self::Weight {
+        Weight::zero()
+    }
+    
+    /// Borrows the ID of the node the edge leaves.
+    fn source(&self) -> &N;
+    
+    /// Borrows the ID of the node the edge enters.
+    fn target(&self) -> &N;
+    
+    fn get_source(&self) -> N where N: Clone {
+        self.source().clone()
+    }
+    
+    fn get_target(&self) -> N where N: Clone {
+        self.target().clone()
+    }
+    
+    fn is_directed(&self) -> bool;
+}
+
+
+
+/// The `Weight` trait is implemented by the numeric types that can be used as edge weights.
+///
+/// It is implemented for all of the primitive integer and floating point types, and can be implemented for user-defined
+/// types to use them as weights.
+pub trait Weight: Copy + PartialOrd {
+    /// Returns the weight of an empty path.
+    fn zero() -> Self;
+    
+    /// Returns the largest representable weight, which algorithms use as the width of a path with no edges.
+    fn max_value() -> Self;
+    
+    /// Returns the smallest representable weight, which algorithms use to represent an infinitely small value.
+    fn min_value() -> Self;
+    
+    /// Adds two weights, returning `None` if the addition overflowed.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    
+    /// Subtracts a weight from another, returning `None` if the subtraction overflowed.
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+    
+    /// Multiplies two weights, returning `None` if the multiplication overflowed.
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+    
+    /// Returns whether the weight can be ordered against itself, which is false for NaN floats.
+    fn is_comparable(&self) -> bool {
+        self.partial_cmp(self).is_some()
+    }
+}
+
+macro_rules! impl_integer_weight {
+    ($($t:ty)*) => ($(
+        impl Weight for $t {
+            fn zero() -> $t { 0 }
+            
+            fn max_value() -> $t { <$t>::max_value() }
+            
+            fn min_value() -> $t { <$t>::min_value() }
+            
+            fn checked_add(&self, other: &$t) -> Option<$t> { (*self).checked_add(*other) }
+            
+            fn checked_sub(&self, other: &$t) -> Option<$t> { (*self).checked_sub(*other) }
+            
+            fn checked_mul(&self, other: &$t) -> Option<$t> { (*self).checked_mul(*other) }
+        }
+    )*)
+}
+
+macro_rules! impl_float_weight {
+    ($($t:ident)*) => ($(
+        impl Weight for $t {
+            fn zero() -> $t { 0.0 }
+            
+            fn max_value() -> $t { ::std::$t::INFINITY }
+            
+            fn min_value() -> $t { ::std::$t::NEG_INFINITY }
+            
+            fn checked_add(&self, other: &$t) -> Option<$t> {
+                let sum = *self + *other;
+                if sum.is_nan() { None } else { Some(sum) }
+            }
+            
+            fn checked_sub(&self, other: &$t) -> Option<$t> {
+                let difference = *self - *other;
+                if difference.is_nan() { None } else { Some(difference) }
+            }
+            
+            fn checked_mul(&self, other: &$t) -> Option<$t> {
+                let product = *self * *other;
+                if product.is_nan() { None } else { Some(product) }
+            }
+        }
+    )*)
+}
+
+impl_integer_weight!(i8 i16 i32 i64 isize u8 u16 u32 u64 usize);
+impl_float_weight!(f32 f64);
+
+
+
+/// The error type returned by graph operations and algorithms.
+///
+/// Each variant carries the IDs of the nodes involved so that callers can tell failures apart without inspecting text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphError<N> {
+    /// The node does not exist in the graph.
+    NodeNotFound(N),
+    /// There is no edge from the first node to the second node.
+    EdgeNotFound(N, N),
+    /// There is no path from the first node to the second node.
+    Unreachable(N, N),
+    /// The edge from the first node to the second node has a negative weight where only non-negative weights are supported.
+    NegativeWeight(N, N),
+    /// Adding the weight of the edge from the first node to the second node to a distance overflowed the weight type.
+    WeightOverflow(N, N),
+    /// The graph contains a cycle whose total weight is negative, given as the nodes in the order they are visited.
+    NegativeCycle(Vec<N>),
+    /// The graph contains a cycle where an acyclic graph is required, given as the nodes in the order they are visited.
+    Cycle(Vec<N>),
+    /// The graph has no Eulerian path or circuit because the degrees of the given nodes do not allow one.
+    NotEulerian(Vec<N>),
+    /// The bookkeeping of an algorithm was found to be inconsistent while processing the node.
+    InternalInconsistency(N)
+}
+
+impl<N> fmt::Display for GraphError<N>
+    where N: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GraphError::NodeNotFound(ref n) => write!(f, "node {:?} does not exist in the graph", n),
+            GraphError::EdgeNotFound(ref s, ref t) => write!(f, "no edge exists from {:?} to {:?}", s, t),
+            GraphError::Unreachable(ref s, ref t) => write!(f, "node {:?} is not reachable from {:?}", t, s),
+            GraphError::NegativeWeight(ref s, ref t) => write!(f, "the edge from {:?} to {:?} has a negative weight", s, t),
+            GraphError::WeightOverflow(ref s, ref t) => write!(f, "the distance along the edge from {:?} to {:?} overflowed", s, t),
+            GraphError::NegativeCycle(ref c) => write!(f, "the graph contains a negative weight cycle {:?}", c),
+            GraphError::Cycle(ref c) => write!(f, "the graph contains a cycle {:?}", c),
+            GraphError::NotEulerian(ref n) => write!(f, "the degrees of the nodes {:?} do not allow an Eulerian path or circuit", n),
+            GraphError::InternalInconsistency(ref n) => write!(f, "inconsistent algorithm state at node {:?}", n)
+        }
+    }
+}
+
+impl<N> Error for GraphError<N>
+    where N: fmt::Debug
+{
+    fn description(&self) -> &str {
+        match *self {
+            GraphError::NodeNotFound(_) => "node not found",
+            GraphError::EdgeNotFound(_, _) => "edge not found",
+            GraphError::Unreachable(_, _) => "node unreachable",
+            GraphError::NegativeWeight(_, _) => "negative edge weight",
+            GraphError::WeightOverflow(_, _) => "weight overflow",
+            GraphError::NegativeCycle(_) => "negative cycle",
+            GraphError::Cycle(_) => "cycle",
+            GraphError::NotEulerian(_) => "not eulerian",
+            GraphError::InternalInconsistency(_) => "internal inconsistency"
+        }
+    }
+}
+
+
+
+/// A struct used to represent a path in a graph.
+///
+/// The struct contains the path of vertex IDs and the distance of the path.
+#[derive(Debug, Clone)]
+pub struct GraphPath<N, W = i32> {
+    distance: W,
+    path: Vec<N>
+}
+
+impl<N, W> GraphPath<N, W>
+    where W: Weight
+{
+    fn new() -> GraphPath<N, W> {
+        GraphPath {
+            distance: W::zero(),
+            path: Vec::new()
+        }
+    }
+    
+    fn set_distance(&mut self, distance: W) -> () {
+        self.distance = distance;
+    }
+    
+    fn set_path(&mut self, path: Vec<N>) -> () {
+        self.path = path;
+    }
+    
+    /// Retrieves the distance of the `GraphPath`
+    pub fn get_distance(& self) -> W {
+        self.distance
+    }
+    
+    /// Retrieves the path.
+    ///
+    /// This is a vector of vertex IDs that are in order of visitation.
+    pub fn get_path(& self) -> &Vec<N> {
+        &self.path
+    }
+}
+
+
+
+/// A struct used to represent the shortest distances between all pairs of nodes in a graph.
+///
+/// The matrix also stores the next hop on the shortest path between each pair, so that paths can be reconstructed.
+#[derive(Debug, Clone)]
+pub struct DistanceMatrix<N, W>
+    where N: Eq + Hash
+{
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    distances: Vec<Option<W>>,
+    next: Vec<Option<usize>>
+}
+
+impl<N, W> DistanceMatrix<N, W>
+    where N: Eq + Clone + Hash,
+          W: Weight
+{
+    fn new(nodes: Vec<N>) -> DistanceMatrix<N, W> {
+        let n = nodes.len();
+        let mut indices: HashMap<N, usize> = HashMap::new();
+        let mut distances: Vec<Option<W>> = vec![None; n * n];
+        let mut next: Vec<Option<usize>> = vec![None; n * n];
+        
+        for (i, id) in nodes.iter().enumerate() {
+            indices.insert(id.clone(), i);
+            distances[i * n + i] = Some(W::zero());
+            next[i * n + i] = Some(i);
+        }
+        
+        DistanceMatrix {
+            nodes: nodes,
+            indices: indices,
+            distances: distances,
+            next: next
+        }
+    }
+    
+    fn cell(& self, source: &N, destination: &N) -> Result<usize, GraphError<N>> {
+        let i = match self.indices.get(source) {
+            Some(i) => *i,
+            None => return Err(GraphError::NodeNotFound(source.clone()))
+        };
+        
+        let j = match self.indices.get(destination) {
+            Some(j) => *j,
+            None => return Err(GraphError::NodeNotFound(destination.clone()))
+        };
+        
+        Ok(i * self.nodes.len() + j)
+    }
+    
+    /// Retrieves the nodes that index the rows and columns of the matrix.
+    pub fn get_nodes(& self) -> &Vec<N> {
+        &self.nodes
+    }
+    
+    /// Retrieves the shortest distance between two nodes, or `None` if there is no path between them.
+    pub fn get_distance(& self, source: &N, destination: &N) -> Option<W> {
+        match self.cell(source, destination) {
+            Ok(c) => self.distances[c],
+            Err(_) => None
+        }
+    }
+    
+    /// Retrieves the node that follows the source node on the shortest path between two nodes.
+    pub fn get_next_hop(& self, source: &N, destination: &N) -> Option<&N> {
+        match self.cell(source, destination) {
+            Ok(c) => self.next[c].map(|i| &self.nodes[i]),
+            Err(_) => None
+        }
+    }
+    
+    /// Reconstructs the `GraphPath` between two nodes.
+    ///
+    /// Returns `GraphError::Unreachable` if there is no path between them.
+    pub fn get_path(& self, source: &N, destination: &N) -> Result<GraphPath<N, W>, GraphError<N>> {
+        let n = self.nodes.len();
+        let c = try!(self.cell(source, destination));
+        
+        let distance = match self.distances[c] {
+            Some(distance) => distance,
+            None => return Err(GraphError::Unreachable(source.clone(), destination.clone()))
+        };
+        
+        let j = self.indices[destination];
+        let mut i = self.indices[source];
+        let mut path: Vec<N> = vec![source.clone()];
+        
+        while i != j {
+            i = match self.next[i * n + j] {
+                Some(x) => x,
+                None => return Err(GraphError::InternalInconsistency(self.nodes[i].clone()))
+            };
+            
+            path.push(self.nodes[i].clone());
+        }
+        
+        let mut result: GraphPath<N, W> = GraphPath::new();
+        result.set_distance(distance);
+        result.set_path(path);
+        
+        Ok(result)
+    }
+}
+
+
+
+/// The algorithm used by `Graph::max_flow` to find augmenting paths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaxFlowAlgorithm {
+    /// Augments along shortest paths found with a breadth first search.
+    EdmondsKarp,
+    /// Augments along blocking flows in the level graph of a breadth first search.
+    Dinic
+}
+
+/// A struct used to represent a maximum flow through a graph.
+///
+/// The flow on each edge is keyed by the edge, and edges that carry no flow have no entry. An undirected edge is keyed in
+/// the direction its flow goes, so an edge whose flow runs from its target to its source is keyed by the reversed edge.
+#[derive(Debug, Clone)]
+pub struct MaxFlow<E, W>
+    where E: Eq + Hash
+{
+    value: W,
+    flows: HashMap<E, W>
+}
+
+impl<E, W> MaxFlow<E, W>
+    where E: Eq + Hash,
+          W: Weight
+{
+    /// Retrieves the total value of the flow.
+    pub fn get_value(& self) -> W {
+        self.value
+    }
+    
+    /// Retrieves the flow on an edge, or `None` if the edge carries no flow in that direction.
+    pub fn get_flow(& self, edge: &E) -> Option<W> {
+        self.flows.get(edge).cloned()
+    }
+    
+    /// Retrieves the flow on every edge that carries any.
+    pub fn get_flows(& self) -> &HashMap<E, W> {
+        &self.flows
+    }
+}
+
+
+
+/// A struct used to represent a maximum flow through a graph along with its total cost.
+///
+/// The flow on each edge is keyed in the same way as in `MaxFlow`, and edges that carry no flow have no entry.
+#[derive(Debug, Clone)]
+pub struct MinCostFlow<E, W>
+    where E: Eq + Hash
+{
+    cost: W,
+    value: W,
+    flows: HashMap<E, W>
+}
+
+impl<E, W> MinCostFlow<E, W>
+    where E: Eq + Hash,
+          W: Weight
+{
+    /// Retrieves the total cost of the flow.
+    pub fn get_cost(& self) -> W {
+        self.cost
+    }
+    
+    /// Retrieves the total value of the flow.
+    pub fn get_value(& self) -> W {
+        self.value
+    }
+    
+    /// Retrieves the flow on an edge, or `None` if the edge carries no flow in that direction.
+    pub fn get_flow(& self, edge: &E) -> Option<W> {
+        self.flows.get(edge).cloned()
+    }
+    
+    /// Retrieves the flow on every edge that carries any.
+    pub fn get_flows(& self) -> &HashMap<E, W> {
+        &self.flows
+    }
+}
+
+
+
+/// A struct used to represent a cut of a graph, which partitions its nodes into two sides.
+///
+/// The cut edges are the edges that cross from the source side to the sink side, and the weight of the cut is the total
+/// weight of those edges.
+#[derive(Debug, Clone)]
+pub struct MinCut<N, E, W> {
+    weight: W,
+    source_side: Vec<N>,
+    sink_side: Vec<N>,
+    edges: Vec<E>
+}
+
+impl<N, E, W> MinCut<N, E, W>
+    where W: Weight
+{
+    /// Retrieves the total weight of the cut edges.
+    pub fn get_weight(& self) -> W {
+        self.weight
+    }
+    
+    /// Retrieves the nodes on the source side of the cut.
+    pub fn get_source_side(& self) -> &Vec<N> {
+        &self.source_side
+    }
+    
+    /// Retrieves the nodes on the sink side of the cut.
+    pub fn get_sink_side(& self) -> &Vec<N> {
+        &self.sink_side
+    }
+    
+    /// Retrieves the edges that cross the cut.
+    pub fn get_edges(& self) -> &Vec<E> {
+        &self.edges
+    }
+}
+
+
+
+/// A struct used to represent a node visited during a traversal of a graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraversalStep<N> {
+    node: N,
+    depth: u32,
+    parent: Option<N>
+}
+
+impl<N> TraversalStep<N> {
+    fn new(node: N, depth: u32, parent: Option<N>) -> TraversalStep<N> {
+        TraversalStep {
+            node: node,
+            depth: depth,
+            parent: parent
+        }
+    }
+    
+    /// Retrieves the ID of the visited node.
+    pub fn get_node(& self) -> &N {
+        &self.node
+    }
+    
+    /// Retrieves the number of edges between the start node and the visited node in the traversal tree.
+    pub fn get_depth(& self) -> u32 {
+        self.depth
+    }
+    
+    /// Retrieves the node from which the visited node was discovered, or `None` for the start node.
+    pub fn get_parent(& self) -> Option<&N> {
+        self.parent.as_ref()
+    }
+}
+
+
+
+/// A lazy breadth first traversal of a graph, created by `Graph::bfs`.
+pub struct BreadthFirstSearch<'a, N: 'a, E: 'a, G: 'a> {
+    graph: &'a G,
+    queue: VecDeque<TraversalStep<N>>,
+    discovered: HashSet<N>,
+    edge: PhantomData<E>
+}
+
+impl<'a, N, E, G> Iterator for BreadthFirstSearch<'a, N, E, G>
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>,
+          G: Graph<N, E>
+{
+    type Item = TraversalStep<N>;
+    
+    fn next(&mut self) -> Option<TraversalStep<N>> {
+        let step = match self.queue.pop_front() {
+            Some(step) => step,
+            None => return None
+        };
+        
+        for (id, _) in self.graph.neighbours(&step.node) {
+            if !self.discovered.contains(id) {
+                self.discovered.insert(id.clone());
+                self.queue.push_back(TraversalStep::new(id.clone(), step.depth + 1, Some(step.node.clone())));
+            }
+        }
+        
+        Some(step)
+    }
+}
 
 
-/// A struct used to represent a path in a graph.
+
+/// A lazy depth first traversal of a graph, created by `Graph::dfs`.
+pub struct DepthFirstSearch<'a, N: 'a, E: 'a, G: 'a> {
+    graph: &'a G,
+    stack: Vec<TraversalStep<N>>,
+    discovered: HashSet<N>,
+    edge: PhantomData<E>
+}
+
+impl<'a, N, E, G> Iterator for DepthFirstSearch<'a, N, E, G>
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>,
+          G: Graph<N, E>
+{
+    type Item = TraversalStep<N>;
+    
+    fn next(&mut self) -> Option<TraversalStep<N>> {
+        loop {
+            let step = match self.stack.pop() {
+                Some(step) => step,
+                None => return None
+            };
+            
+            if self.discovered.contains(&step.node) {
+                continue;
+            }
+            
+            self.discovered.insert(step.node.clone());
+            
+            let neighbours: Vec<&N> = self.graph.neighbours(&step.node).map(|(id, _)| id).collect();
+            for id in neighbours.into_iter().rev() {
+                if !self.discovered.contains(id) {
+                    self.stack.push(TraversalStep::new(id.clone(), step.depth + 1, Some(step.node.clone())));
+                }
+            }
+            
+            return Some(step);
+        }
+    }
+}
+
+
+
+/// A struct used to represent the connected components of a graph.
 ///
-/// The struct contains the path of vertex IDs and the distance of the path.
+/// Components are identified by IDs from 0 up to the number of components.
 #[derive(Debug, Clone)]
-pub struct GraphPath<N> {
-    distance: i32,
-    path: Vec<N>
+pub struct ConnectedComponents<N>
+    where N: Eq + Hash
+{
+    labels: HashMap<N, usize>,
+    components: Vec<Vec<N>>
 }
 
-impl<N> GraphPath<N> {
-    fn new() -> GraphPath<N> {
-        GraphPath {
-            distance: 0,
-            path: Vec::new()
+impl<N> ConnectedComponents<N>
+    where N: Eq + Hash
+{
+    fn new() -> ConnectedComponents<N> {
+        ConnectedComponents {
+            labels: HashMap::new(),
+            components: Vec::new()
+        }
+    }
+    
+    /// Retrieves the ID of the component that a node belongs to, or `None` if the node is not in the graph.
+    pub fn get_component(& self, node: &N) -> Option<usize> {
+        self.labels.get(node).cloned()
+    }
+    
+    /// Retrieves the members of each component, indexed by component ID.
+    pub fn get_components(& self) -> &Vec<Vec<N>> {
+        &self.components
+    }
+    
+    /// Retrieves the number of components.
+    pub fn count(& self) -> usize {
+        self.components.len()
+    }
+}
+
+
+
+/// An event reported to the visitor passed to `Graph::dfs_visit`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DfsEvent<N> {
+    /// The node has been discovered.
+    Discover(N),
+    /// The edge from the first node discovered the second node.
+    TreeEdge(N, N),
+    /// The edge from the first node leads to an ancestor of it in the depth first search tree.
+    BackEdge(N, N),
+    /// The edge from the first node leads to an already finished descendant of it in the depth first search tree.
+    ForwardEdge(N, N),
+    /// The edge from the first node leads to an already finished node that is neither an ancestor nor a descendant of it.
+    CrossEdge(N, N),
+    /// All edges leaving the node have been explored.
+    Finish(N)
+}
+
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Private functions used in the graph trait provided functions
+////////////////////////////////////////////////////////////////////////////////
+
+/// A struct used to store the state of a depth first search so that it can be shared between searches from several nodes.
+struct DfsState<N> {
+    discovery: HashMap<N, usize>,
+    finished: HashSet<N>
+}
+
+impl<N> DfsState<N>
+    where N: Eq + Clone + Hash
+{
+    fn new() -> DfsState<N> {
+        DfsState {
+            discovery: HashMap::new(),
+            finished: HashSet::new()
+        }
+    }
+}
+
+/// A struct used to store a node on the explicit stack of a depth first search.
+struct DfsFrame<N> {
+    node: N,
+    parent: Option<N>,
+    skipped_parent_edge: bool,
+    neighbours: Vec<(N, bool)>,
+    next: usize
+}
+
+fn depth_first_visit<N, E, G, F>(graph: &G, start: &N, state: &mut DfsState<N>, visitor: &mut F) -> ()
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>,
+          G: Graph<N, E>,
+          F: FnMut(DfsEvent<N>)
+{
+    if state.discovery.contains_key(start) {
+        return;
+    }
+    
+    let mut stack: Vec<DfsFrame<N>> = Vec::new();
+    
+    let time = state.discovery.len();
+    state.discovery.insert(start.clone(), time);
+    visitor(DfsEvent::Discover(start.clone()));
+    stack.push(DfsFrame {
+        node: start.clone(),
+        parent: None,
+        skipped_parent_edge: false,
+        neighbours: graph.neighbours(start).map(|(id, e)| (id.clone(), e.is_directed())).collect(),
+        next: 0
+    });
+    
+    while !stack.is_empty() {
+        let top = stack.len() - 1;
+        
+        if stack[top].next == stack[top].neighbours.len() {
+            let frame = stack.pop().unwrap();
+            state.finished.insert(frame.node.clone());
+            visitor(DfsEvent::Finish(frame.node));
+            continue;
+        }
+        
+        let (id, directed) = stack[top].neighbours[stack[top].next].clone();
+        let node = stack[top].node.clone();
+        stack[top].next += 1;
+        
+        if !directed && !stack[top].skipped_parent_edge && stack[top].parent.as_ref() == Some(&id) {
+            stack[top].skipped_parent_edge = true;
+            continue;
+        }
+        
+        if !state.discovery.contains_key(&id) {
+            let time = state.discovery.len();
+            state.discovery.insert(id.clone(), time);
+            visitor(DfsEvent::TreeEdge(node.clone(), id.clone()));
+            visitor(DfsEvent::Discover(id.clone()));
+            stack.push(DfsFrame {
+                neighbours: graph.neighbours(&id).map(|(id, e)| (id.clone(), e.is_directed())).collect(),
+                node: id,
+                parent: Some(node),
+                skipped_parent_edge: false,
+                next: 0
+            });
+        } else if !state.finished.contains(&id) {
+            visitor(DfsEvent::BackEdge(node, id));
+        } else if directed {
+            if state.discovery[&node] < state.discovery[&id] {
+                visitor(DfsEvent::ForwardEdge(node, id));
+            } else {
+                visitor(DfsEvent::CrossEdge(node, id));
+            }
+        }
+    }
+}
+
+/// Runs a depth first search over every node of a graph, returning the first cycle found through a back edge along with
+/// the nodes in the order they finished.
+fn perform_cycle_search<N, E, G>(graph: &G) -> (Option<Vec<N>>, Vec<N>)
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>,
+          G: Graph<N, E>
+{
+    let mut state: DfsState<N> = DfsState::new();
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut cycle: Option<Vec<N>> = None;
+    let mut order: Vec<N> = Vec::new();
+    
+    {
+        let mut visitor = |event: DfsEvent<N>| {
+            match event {
+                DfsEvent::TreeEdge(u, v) => { parents.insert(v, u); },
+                DfsEvent::BackEdge(u, v) => {
+                    if cycle.is_none() {
+                        let mut nodes: Vec<N> = vec![u.clone()];
+                        let mut last = u;
+                        
+                        while last != v {
+                            last = parents[&last].clone();
+                            nodes.push(last.clone());
+                        }
+                        
+                        nodes.reverse();
+                        cycle = Some(nodes);
+                    }
+                },
+                DfsEvent::Finish(u) => order.push(u),
+                _ => ()
+            }
+        };
+        
+        for id in graph.nodes() {
+            depth_first_visit(graph, id, &mut state, &mut visitor);
+        }
+    }
+    
+    (cycle, order)
+}
+
+/// Runs a depth first search over every node of a graph that tracks low-link values, returning the articulation points,
+/// the bridges and the edges of each biconnected component as pairs of nodes.
+fn perform_biconnected_search<N, E, G>(graph: &G) -> (Vec<N>, Vec<(N, N)>, Vec<Vec<(N, N)>>)
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>,
+          G: Graph<N, E>
+{
+    let mut state: DfsState<N> = DfsState::new();
+    let mut indices: HashMap<N, usize> = HashMap::new();
+    let mut low_links: HashMap<N, usize> = HashMap::new();
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut children: HashMap<N, usize> = HashMap::new();
+    let mut stack: Vec<(N, N)> = Vec::new();
+    let mut points: HashSet<N> = HashSet::new();
+    let mut bridges: Vec<(N, N)> = Vec::new();
+    let mut components: Vec<Vec<(N, N)>> = Vec::new();
+    
+    {
+        let mut visitor = |event: DfsEvent<N>| {
+            match event {
+                DfsEvent::Discover(u) => {
+                    let index = indices.len();
+                    indices.insert(u.clone(), index);
+                    low_links.insert(u, index);
+                },
+                DfsEvent::TreeEdge(u, v) => {
+                    *children.entry(u.clone()).or_insert(0) += 1;
+                    parents.insert(v.clone(), u.clone());
+                    stack.push((u, v));
+                },
+                DfsEvent::BackEdge(u, v) => {
+                    if u != v {
+                        if indices[&v] < low_links[&u] {
+                            low_links.insert(u.clone(), indices[&v]);
+                        }
+                        
+                        stack.push((u, v));
+                    }
+                },
+                DfsEvent::Finish(v) => {
+                    let p = match parents.get(&v) {
+                        Some(x) => x.clone(),
+                        None => return
+                    };
+                    let low_link = low_links[&v];
+                    
+                    if low_link < low_links[&p] {
+                        low_links.insert(p.clone(), low_link);
+                    }
+                    
+                    if low_link >= indices[&p] {
+                        let mut component: Vec<(N, N)> = Vec::new();
+                        
+                        while let Some(edge) = stack.pop() {
+                            let done = edge.0 == p && edge.1 == v;
+                            component.push(edge);
+                            
+                            if done {
+                                break;
+                            }
+                        }
+                        
+                        components.push(component);
+                        
+                        if parents.contains_key(&p) {
+                            points.insert(p.clone());
+                        }
+                    }
+                    
+                    if low_link > indices[&p] {
+                        bridges.push((p, v));
+                    }
+                },
+                _ => ()
+            }
+        };
+        
+        for id in graph.nodes() {
+            depth_first_visit(graph, id, &mut state, &mut visitor);
+        }
+    }
+    
+    for (id, count) in children.iter() {
+        if *count > 1 && !parents.contains_key(id) {
+            points.insert(id.clone());
+        }
+    }
+    
+    (points.into_iter().collect(), bridges, components)
+}
+
+/// Follows unused edges from a node using Hierholzer's algorithm, splicing in detours until every edge has been used.
+///
+/// Returns `GraphError::Unreachable` if some edges could not be reached from the start node.
+fn find_eulerian_trail<N, E, G>(graph: &G, start: &N, directed: bool) -> Result<Vec<N>, GraphError<N>>
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>,
+          G: Graph<N, E>
+{
+    let edges: Vec<&E> = graph.edges().collect();
+    let mut adjacency: HashMap<&N, Vec<(&N, usize)>> = HashMap::new();
+    let mut next: HashMap<&N, usize> = HashMap::new();
+    let mut used: Vec<bool> = vec![false; edges.len()];
+    let mut stack: Vec<&N> = vec![start];
+    let mut trail: Vec<N> = Vec::new();
+    
+    for (i, e) in edges.iter().enumerate() {
+        adjacency.entry(e.source()).or_insert(Vec::new()).push((e.target(), i));
+        
+        if !directed {
+            adjacency.entry(e.target()).or_insert(Vec::new()).push((e.source(), i));
+        }
+    }
+    
+    while let Some(&u) = stack.last() {
+        let position = next.entry(u).or_insert(0);
+        let mut step = None;
+        
+        match adjacency.get(u) {
+            Some(x) => {
+                while *position < x.len() && used[x[*position].1] {
+                    *position += 1;
+                }
+                
+                if *position < x.len() {
+                    step = Some(x[*position]);
+                }
+            },
+            None => ()
+        }
+        
+        match step {
+            Some((v, i)) => {
+                used[i] = true;
+                stack.push(v);
+            },
+            None => {
+                trail.push(u.clone());
+                stack.pop();
+            }
+        }
+    }
+    
+    match used.iter().position(|x| !*x) {
+        Some(i) => return Err(GraphError::Unreachable(start.clone(), edges[i].get_source())),
+        None => ()
+    }
+    
+    trail.reverse();
+    
+    Ok(trail)
+}
+
+/// A struct used to hand out the edges between each pair of nodes one at a time, so that parallel edges are told apart.
+///
+/// The edges are grouped by the pair of nodes they join up front, and a cursor per pair is shared by both directions of
+/// the pair, so taking every edge of the graph takes O(V + E) time.
+struct PairEdges<'a, N: 'a, E: 'a> {
+    edges: HashMap<(N, N), Vec<&'a E>>,
+    cursors: HashMap<(N, N), usize>
+}
+
+impl<'a, N, E> PairEdges<'a, N, E>
+    where N: 'a + Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>
+{
+    fn new<G>(graph: &'a G) -> PairEdges<'a, N, E> where G: Graph<N, E> {
+        let mut edges: HashMap<(N, N), Vec<&'a E>> = HashMap::new();
+        
+        for id in graph.nodes() {
+            for (neighbour, edge) in graph.neighbours(id) {
+                edges.entry((id.clone(), neighbour.clone())).or_insert(Vec::new()).push(edge);
+            }
+        }
+        
+        PairEdges {
+            edges: edges,
+            cursors: HashMap::new()
+        }
+    }
+    
+    /// Returns the next edge from one node to another that has not been taken yet.
+    fn take(&mut self, source: &N, target: &N) -> Option<E> {
+        let key = (source.clone(), target.clone());
+        let cursor = self.cursors.get(&key).cloned().unwrap_or(0);
+        
+        self.cursors.insert(key.clone(), cursor + 1);
+        self.cursors.insert((target.clone(), source.clone()), cursor + 1);
+        
+        self.edges.get(&key).and_then(|x| x.get(cursor)).map(|e| (*e).clone())
+    }
+}
+
+/// A struct used to store an arc of a residual network along with its remaining capacity.
+///
+/// The cost of an arc is added when flow is sent along it, except for the reverse arcs at odd indices, which undo flow
+/// and so subtract their cost.
+struct ResidualArc<W> {
+    target: usize,
+    capacity: W,
+    residual: W,
+    cost: W
+}
+
+/// A struct used to store the residual network of a flow, where the arcs of each edge are stored next to each other so
+/// that the reverse of arc `i` is arc `i ^ 1`.
+struct ResidualNetwork<N, W> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    adjacency: Vec<Vec<usize>>,
+    arcs: Vec<ResidualArc<W>>
+}
+
+impl<N, W> ResidualNetwork<N, W>
+    where N: Eq + Clone + Hash,
+          W: Weight
+{
+    /// Creates a residual network holding the given nodes and no arcs.
+    fn with_nodes(nodes: Vec<N>) -> ResidualNetwork<N, W> {
+        let mut network = ResidualNetwork {
+            nodes: nodes,
+            indices: HashMap::new(),
+            adjacency: Vec::new(),
+            arcs: Vec::new()
+        };
+        
+        for (i, id) in network.nodes.iter().enumerate() {
+            network.indices.insert(id.clone(), i);
+            network.adjacency.push(Vec::new());
+        }
+        
+        network
+    }
+    
+    /// Creates the residual network of a graph with no flow, giving each undirected edge its capacity in both directions.
+    fn new<E, G>(graph: &G) -> Result<ResidualNetwork<N, W>, GraphError<N>>
+        where E: Eq + Clone + Hash + Edge<N, Weight=W>,
+              G: Graph<N, E>
+    {
+        let mut network: ResidualNetwork<N, W> = ResidualNetwork::with_nodes(graph.get_nodes());
+        
+        for e in graph.edges() {
+            let capacity = e.get_weight();
+            
+            if capacity < W::zero() {
+                return Err(GraphError::NegativeWeight(e.get_source(), e.get_target()));
+            }
+            
+            let source = network.indices[e.source()];
+            let target = network.indices[e.target()];
+            let reverse = if e.is_directed() { W::zero() } else { capacity };
+            
+            network.add_arcs(source, target, capacity, reverse, W::zero());
+        }
+        
+        Ok(network)
+    }
+    
+    /// Creates the residual network of a graph with no flow and a cost on each arc.
+    ///
+    /// Each undirected edge becomes a pair of opposite directed edges with its capacity and cost, so that flow sent one
+    /// way is not mistaken for flow cancelled the other way. Returns the network along with the index of the forward arc
+    /// for each edge of the graph in each of its directions.
+    fn with_costs<E, G>(graph: &G) -> Result<(ResidualNetwork<N, W>, Vec<(usize, Option<usize>)>), GraphError<N>>
+        where E: Eq + Clone + Hash + Edge<N, Weight=W>,
+              G: Graph<N, E>
+    {
+        let mut network: ResidualNetwork<N, W> = ResidualNetwork::with_nodes(graph.get_nodes());
+        let mut edge_arcs: Vec<(usize, Option<usize>)> = Vec::new();
+        
+        for e in graph.edges() {
+            let capacity = e.get_weight();
+            let edge_cost = e.get_cost();
+            
+            if capacity < W::zero() || edge_cost < W::zero() {
+                return Err(GraphError::NegativeWeight(e.get_source(), e.get_target()));
+            }
+            
+            let source = network.indices[e.source()];
+            let target = network.indices[e.target()];
+            
+            let forward = network.add_arcs(source, target, capacity, W::zero(), edge_cost);
+            let backward = if e.is_directed() { None } else { Some(network.add_arcs(target, source, capacity, W::zero(), edge_cost)) };
+            
+            edge_arcs.push((forward, backward));
+        }
+        
+        Ok((network, edge_arcs))
+    }
+    
+    /// Adds an arc and its reverse arc between two nodes, returning the index of the forward arc.
+    fn add_arcs(&mut self, source: usize, target: usize, capacity: W, reverse: W, cost: W) -> usize {
+        let index = self.arcs.len();
+        
+        self.adjacency[source].push(index);
+        self.arcs.push(ResidualArc { target: target, capacity: capacity, residual: capacity, cost: cost });
+        self.adjacency[target].push(index + 1);
+        self.arcs.push(ResidualArc { target: source, capacity: reverse, residual: reverse, cost: cost });
+        
+        index
+    }
+    
+    /// Sends flow along a path of arcs, lowering their remaining capacity and raising that of their reverse arcs.
+    fn augment(&mut self, path: &Vec<usize>, amount: W) -> Result<(), GraphError<N>> {
+        for &a in path {
+            let forward = self.arcs[a].residual.checked_sub(&amount);
+            let backward = self.arcs[a ^ 1].residual.checked_add(&amount);
+            
+            match (forward, backward) {
+                (Some(x), Some(y)) => {
+                    self.arcs[a].residual = x;
+                    self.arcs[a ^ 1].residual = y;
+                },
+                _ => return Err(GraphError::WeightOverflow(self.nodes[self.arcs[a ^ 1].target].clone(), self.nodes[self.arcs[a].target].clone()))
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Returns the smallest remaining capacity along a path of arcs.
+    fn bottleneck(& self, path: &Vec<usize>) -> W {
+        let mut result = W::max_value();
+        
+        for &a in path {
+            if self.arcs[a].residual < result {
+                result = self.arcs[a].residual;
+            }
+        }
+        
+        result
+    }
+    
+    /// Finds the breadth first search level of every node reachable from a node through arcs with remaining capacity,
+    /// along with the arc used to reach each of them.
+    fn levels(& self, source: usize) -> (Vec<Option<usize>>, Vec<Option<usize>>) {
+        let mut levels: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut parents: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        
+        levels[source] = Some(0);
+        queue.push_back(source);
+        
+        while let Some(u) = queue.pop_front() {
+            for &a in &self.adjacency[u] {
+                let v = self.arcs[a].target;
+                
+                if levels[v].is_none() && self.arcs[a].residual > W::zero() {
+                    levels[v] = levels[u].map(|x| x + 1);
+                    parents[v] = Some(a);
+                    queue.push_back(v);
+                }
+            }
+        }
+        
+        (levels, parents)
+    }
+}
+
+/// Adds a weight to the edge between two nodes in the adjacency maps of a graph whose nodes are being merged.
+fn add_merged_weight<N, W>(adjacency: &mut Vec<HashMap<usize, W>>, u: usize, v: usize, weight: W, nodes: &Vec<N>) -> Result<(), GraphError<N>>
+    where N: Clone,
+          W: Weight
+{
+    let total = match adjacency[u].get(&v) {
+        Some(x) => x.checked_add(&weight),
+        None => Some(weight)
+    };
+    
+    match total {
+        Some(x) => {
+            adjacency[u].insert(v, x);
+            Ok(())
+        },
+        None => Err(GraphError::WeightOverflow(nodes[u].clone(), nodes[v].clone()))
+    }
+}
+
+/// Builds the residual network of a graph and pushes the maximum flow from the source to the sink through it, returning
+/// the network along with the value of the flow.
+fn compute_max_flow<N, E, G>(graph: &G, source: &N, sink: &N, algorithm: MaxFlowAlgorithm) -> Result<(ResidualNetwork<N, E::Weight>, E::Weight), GraphError<N>>
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>,
+          G: Graph<N, E>
+{
+    if !graph.is_node_in_graph(source) {
+        return Err(GraphError::NodeNotFound(source.clone()));
+    }
+    
+    if !graph.is_node_in_graph(sink) {
+        return Err(GraphError::NodeNotFound(sink.clone()));
+    }
+    
+    let mut network: ResidualNetwork<N, E::Weight> = try!(ResidualNetwork::new(graph));
+    let s = network.indices[source];
+    let t = network.indices[sink];
+    let mut value: E::Weight = Weight::zero();
+    
+    if s == t {
+        return Ok((network, value));
+    }
+    
+    loop {
+        let (levels, parents) = network.levels(s);
+        
+        if levels[t].is_none() {
+            break;
+        }
+        
+        let pushed = match algorithm {
+            MaxFlowAlgorithm::EdmondsKarp => {
+                let mut path: Vec<usize> = Vec::new();
+                let mut v = t;
+                
+                while v != s {
+                    let a = parents[v].unwrap();
+                    path.push(a);
+                    v = network.arcs[a ^ 1].target;
+                }
+                
+                let amount = network.bottleneck(&path);
+                try!(network.augment(&path, amount));
+                amount
+            },
+            MaxFlowAlgorithm::Dinic => try!(perform_blocking_flow(&mut network, &levels, s, t))
+        };
+        
+        value = match value.checked_add(&pushed) {
+            Some(x) => x,
+            None => return Err(GraphError::WeightOverflow(source.clone(), sink.clone()))
+        };
+    }
+    
+    Ok((network, value))
+}
+
+/// Finds the cheapest distance to every node of a residual network using Dijkstra's algorithm, along with the arc used
+/// to reach each of them. Nodes that cannot be reached have no distance.
+///
+/// Each node is keyed in the heap by its distance less its potential, which is its distance in the previous search. The
+/// reduced costs this gives are never negative, even on the reverse arcs that subtract their cost.
+fn find_cheapest_path<N, W>(network: &ResidualNetwork<N, W>, potentials: &Vec<W>, source: usize) -> Result<(Vec<Option<W>>, Vec<Option<usize>>), GraphError<N>>
+    where N: Eq + Clone + Hash,
+          W: Weight
+{
+    let mut distances: Vec<Option<W>> = vec![None; network.nodes.len()];
+    let mut parents: Vec<Option<usize>> = vec![None; network.nodes.len()];
+    let mut visited: Vec<bool> = vec![false; network.nodes.len()];
+    let mut heap: FibonacciHeap<HeapKey<W>, usize> = FibonacciHeap::new();
+    
+    distances[source] = Some(W::zero());
+    heap.insert(HeapKey(W::zero()), source);
+    
+    while heap.minimum().is_some() {
+        let u = heap.extract_min().unwrap().1;
+        let distance = match distances[u] {
+            Some(x) => x,
+            None => return Err(GraphError::InternalInconsistency(network.nodes[u].clone()))
+        };
+        
+        visited[u] = true;
+        
+        for &a in &network.adjacency[u] {
+            let arc = &network.arcs[a];
+            let v = arc.target;
+            
+            if visited[v] || arc.residual <= W::zero() {
+                continue;
+            }
+            
+            let length = if a % 2 == 0 { distance.checked_add(&arc.cost) } else { distance.checked_sub(&arc.cost) };
+            let length = match length {
+                Some(x) => x,
+                None => return Err(GraphError::WeightOverflow(network.nodes[u].clone(), network.nodes[v].clone()))
+            };
+            
+            let reached = distances[v].is_some();
+            let is_shorter = match distances[v] {
+                Some(x) => length < x,
+                None => true
+            };
+            
+            if is_shorter {
+                let key = match length.checked_sub(&potentials[v]) {
+                    Some(x) => x,
+                    None => return Err(GraphError::InternalInconsistency(network.nodes[v].clone()))
+                };
+                
+                distances[v] = Some(length);
+                parents[v] = Some(a);
+                
+                if reached {
+                    let _ = heap.decrease_key(v, HeapKey(key));
+                } else {
+                    heap.insert(HeapKey(key), v);
+                }
+            }
         }
     }
     
-    fn set_distance(&mut self, distance: i32) -> () {
-        self.distance = distance;
+    Ok((distances, parents))
+}
+
+/// Adds flow to the entry of an edge in a map of flows, returning `GraphError::WeightOverflow` if it overflowed.
+fn add_edge_flow<N, E>(flows: &mut HashMap<E, E::Weight>, edge: E, flow: Option<E::Weight>) -> Result<(), GraphError<N>>
+    where N: Clone,
+          E: Eq + Hash + Edge<N>
+{
+    if flow == Some(Weight::zero()) {
+        return Ok(());
     }
     
-    fn set_path(&mut self, path: Vec<N>) -> () {
-        self.path = path;
+    let total = match (flows.get(&edge), flow) {
+        (Some(x), Some(y)) => x.checked_add(&y),
+        (None, y) => y,
+        _ => None
+    };
+    
+    match total {
+        Some(x) => {
+            flows.insert(edge, x);
+            Ok(())
+        },
+        None => Err(GraphError::WeightOverflow(edge.get_source(), edge.get_target()))
     }
+}
+
+/// Pushes a blocking flow through the level graph of a residual network, returning the amount of flow pushed.
+///
+/// Each node keeps a pointer to the next arc to try so that arcs leading to dead ends are only tried once.
+fn perform_blocking_flow<N, W>(network: &mut ResidualNetwork<N, W>, levels: &Vec<Option<usize>>, s: usize, t: usize) -> Result<W, GraphError<N>>
+    where N: Eq + Clone + Hash,
+          W: Weight
+{
+    let mut next: Vec<usize> = vec![0; network.nodes.len()];
+    let mut dead: Vec<bool> = vec![false; network.nodes.len()];
+    let mut path: Vec<usize> = Vec::new();
+    let mut total = W::zero();
+    let mut u = s;
     
-    /// Retrieves the distance of the `GraphPath`
-    pub fn get_distance(& self) -> i32 {
-        self.distance
+    loop {
+        if u == t {
+            let amount = network.bottleneck(&path);
+            try!(network.augment(&path, amount));
+            
+            total = match total.checked_add(&amount) {
+                Some(x) => x,
+                None => return Err(GraphError::WeightOverflow(network.nodes[s].clone(), network.nodes[t].clone()))
+            };
+            
+            path.clear();
+            u = s;
+            continue;
+        }
+        
+        let mut step = None;
+        
+        while next[u] < network.adjacency[u].len() {
+            let a = network.adjacency[u][next[u]];
+            let v = network.arcs[a].target;
+            
+            if !dead[v] && network.arcs[a].residual > W::zero() && levels[v] == levels[u].map(|x| x + 1) {
+                step = Some(a);
+                break;
+            }
+            
+            next[u] += 1;
+        }
+        
+        match step {
+            Some(a) => {
+                path.push(a);
+                u = network.arcs[a].target;
+            },
+            None => {
+                if u == s {
+                    break;
+                }
+                
+                dead[u] = true;
+                path.pop();
+                u = match path.last() {
+                    Some(&a) => network.arcs[a].target,
+                    None => s
+                };
+            }
+        }
     }
     
-    /// Retrieves the path.
-    ///
-    /// This is a vector of vertex IDs that are in order of visitation.
-    pub fn get_path(& self) -> &Vec<N> {
-        &self.path
+    Ok(total)
+}
+
+/// A key used in the Fibonacci heap so that weights that are only partially ordered, such as floats, can be used as keys.
+#[derive(Clone, Copy)]
+struct HeapKey<W>(W);
+
+impl<W> PartialEq for HeapKey<W>
+    where W: Weight
+{
+    fn eq(&self, other: &HeapKey<W>) -> bool {
+        self.cmp(other) == Ordering::Equal
     }
 }
 
+impl<W> Eq for HeapKey<W> where W: Weight { }
 
+impl<W> PartialOrd for HeapKey<W>
+    where W: Weight
+{
+    fn partial_cmp(&self, other: &HeapKey<W>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-////////////////////////////////////////////////////////////////////////////////
-// Private functions used in the graph trait provided functions
-////////////////////////////////////////////////////////////////////////////////
+impl<W> Ord for HeapKey<W>
+    where W: Weight
+{
+    fn cmp(&self, other: &HeapKey<W>) -> Ordering {
+        compare_weights(&self.0, &other.0)
+    }
+}
 
-fn create_dijkstra_metadata<N>(vertices: &Vec<N>, heap: &mut FibonacciHeap<i32, N>, start_vertex: &N) -> Result<HashMap<N, MetadataDijsktra<N>>, String> 
-    where N: Eq + Clone + Hash
+/// A key used in the Fibonacci heap to extract the largest weight first.
+#[derive(Clone, Copy)]
+struct ReverseHeapKey<W>(W);
+
+impl<W> PartialEq for ReverseHeapKey<W>
+    where W: Weight
+{
+    fn eq(&self, other: &ReverseHeapKey<W>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W> Eq for ReverseHeapKey<W> where W: Weight { }
+
+impl<W> PartialOrd for ReverseHeapKey<W>
+    where W: Weight
+{
+    fn partial_cmp(&self, other: &ReverseHeapKey<W>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W> Ord for ReverseHeapKey<W>
+    where W: Weight
 {
-        let mut metadata: HashMap<N, MetadataDijsktra<N>> = HashMap::new();
+    fn cmp(&self, other: &ReverseHeapKey<W>) -> Ordering {
+        compare_weights(&other.0, &self.0)
+    }
+}
+
+fn compare_weights<W>(a: &W, b: &W) -> Ordering
+    where W: Weight
+{
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
+
+fn create_dijkstra_metadata<'a, N, W, I>(vertices: I, heap: &mut FibonacciHeap<HeapKey<W>, N>, start_vertex: &N) -> Result<HashMap<N, MetadataDijsktra<N, W>>, GraphError<N>> 
+    where N: 'a + Eq + Clone + Hash,
+          W: Weight,
+          I: Iterator<Item=&'a N>
+{
+        let mut metadata: HashMap<N, MetadataDijsktra<N, W>> = HashMap::new();
                 
         for id in vertices {
             if !(id == start_vertex) {
                 metadata.insert(id.clone(), MetadataDijsktra {
                     predecessor: None,
                     visited: false,
-                    distance: i32::MAX
+                    distance: None
                 });
-                heap.insert(i32::MAX, id.clone())
             }
         }
         
         metadata.insert(start_vertex.clone(), MetadataDijsktra {
             predecessor: None,
             visited: false,
-            distance: 0
+            distance: Some(W::zero())
         });
-        heap.insert(0, start_vertex.clone());
+        heap.insert(HeapKey(W::zero()), start_vertex.clone());
         
         Ok(metadata)
 }
 
-fn perform_edge_relaxation<N, E, G>(graph: &G, metadata: &mut HashMap<N, MetadataDijsktra<N>>, heap: &mut FibonacciHeap<i32, N>, min_id: &N) -> Result<(), String> 
+/// Runs Dijkstra's algorithm from a node, stopping early once the destination is visited if one is given.
+///
+/// The heuristic is added to the distance of each node to give its key in the heap, as in A* search. Only the edges
+/// between pairs of nodes for which `allowed` returns true are followed.
+fn dijkstra_search<N, E, G, H, F>(graph: &G, source: &N, destination: Option<&N>, heuristic: &H, allowed: &F) -> Result<HashMap<N, MetadataDijsktra<N, E::Weight>>, GraphError<N>>
     where N: Eq + Clone + Hash,
           E: Eq + Clone + Hash + Edge<N>,
-          G: Graph<N, E>
+          G: Graph<N, E>,
+          H: Fn(&N) -> E::Weight,
+          F: Fn(&N, &N) -> bool
 {
-    for id in &graph.get_node_neighbours(min_id) {
-        let min_id_meta;
-        match metadata.get(min_id).cloned() {
-            Some(x) => min_id_meta = x,
-            None => return Err("An error occured while performing edge relaxation".to_string())
+    let mut metadata: HashMap<N, MetadataDijsktra<N, E::Weight>>;
+    let mut heap: FibonacciHeap<HeapKey<E::Weight>, N> = FibonacciHeap::new();
+    
+    metadata = try!(create_dijkstra_metadata(graph.nodes(), &mut heap, source));
+    
+    while heap.minimum().is_some() {
+        let min_id: N = heap.extract_min().unwrap().1;
+        
+        match metadata.get_mut(&min_id) {
+            Some(ref mut x) => x.visited = true,
+            None => return Err(GraphError::InternalInconsistency(min_id.clone()))
         }
         
+        if destination == Some(&min_id) {
+            break;
+        }
+        
+        let edges = graph.neighbours(&min_id).filter(|&(id, _)| allowed(&min_id, id));
+        try!(perform_edge_relaxation(edges, &mut metadata, &mut heap, &min_id, heuristic));
+    }
+    
+    Ok(metadata)
+}
+
+/// The heuristic used to run the A* machinery as plain Dijkstra's algorithm.
+fn no_heuristic<N, W>(_: &N) -> W
+    where W: Weight
+{
+    W::zero()
+}
+
+/// The edge filter used to let a search follow every edge in the graph.
+fn all_edges<N>(_: &N, _: &N) -> bool {
+    true
+}
+
+/// Relaxes the given edges of `min_id`, keying each improved node in the heap by its distance plus the heuristic.
+///
+/// A node enters the heap when it is first reached. A node that was already visited is put back into the heap if a
+/// shorter path to it is found, which only happens for heuristics that are not consistent.
+fn perform_edge_relaxation<'a, N, E, I, H>(edges: I, metadata: &mut HashMap<N, MetadataDijsktra<N, E::Weight>>, heap: &mut FibonacciHeap<HeapKey<E::Weight>, N>, min_id: &N, heuristic: &H) -> Result<(), GraphError<N>> 
+    where N: 'a + Eq + Clone + Hash,
+          E: 'a + Eq + Clone + Hash + Edge<N>,
+          I: Iterator<Item=(&'a N, &'a E)>,
+          H: Fn(&N) -> E::Weight
+{
+    let distance;
+    match metadata.get(min_id) {
+        Some(&MetadataDijsktra { distance: Some(x), .. }) => distance = x,
+        Some(_) => return Ok(()),
+        None => return Err(GraphError::InternalInconsistency(min_id.clone()))
+    }
+    
+    for (id, edge) in edges {
         let mut id_meta;
         match metadata.get_mut(id) {
             Some(x) => id_meta = x,
-            None => return Err("An error occured while performing edge relaxation".to_string())
+            None => return Err(GraphError::InternalInconsistency(id.clone()))
         }
         
-        if !id_meta.visited {
-            let length = min_id_meta.distance + try!(graph.get_edge(min_id, id)).get_weight();
-            if id_meta.distance > length {
-                id_meta.distance = length;
-                id_meta.predecessor = Some(min_id.clone());
-                let _ = heap.decrease_key(id.clone(), length);
+        let length = match distance.checked_add(&edge.get_weight()) {
+            Some(x) => x,
+            None => return Err(GraphError::WeightOverflow(min_id.clone(), id.clone()))
+        };
+        
+        let is_shorter = match id_meta.distance {
+            Some(x) => x > length,
+            None => true
+        };
+        
+        if is_shorter {
+            let key = match length.checked_add(&heuristic(id)) {
+                Some(x) => x,
+                None => return Err(GraphError::WeightOverflow(min_id.clone(), id.clone()))
+            };
+            
+            let reached = id_meta.distance.is_some();
+            
+            id_meta.distance = Some(length);
+            id_meta.predecessor = Some(min_id.clone());
+            
+            if !reached {
+                heap.insert(HeapKey(key), id.clone());
+            } else if id_meta.visited {
+                id_meta.visited = false;
+                heap.insert(HeapKey(key), id.clone());
+            } else {
+                let _ = heap.decrease_key(id.clone(), HeapKey(key));
             }
         }
     }
@@ -438,16 +3188,364 @@ fn perform_edge_relaxation<N, E, G>(graph: &G, metadata: &mut HashMap<N, Metadat
     Ok(())
 }
 
-fn backtrack_vertex_predecessor<N>(metadata: &HashMap<N, MetadataDijsktra<N>>, start_vertex: &N, target_vertex: &N) -> Result<GraphPath<N>, String>
+/// The outcome of advancing one side of a bidirectional search by a single node.
+enum SearchStep {
+    Continue,
+    Exhausted,
+    Met
+}
+
+/// Visits the next node of one side of a bidirectional search and relaxes its edges, recording in `best` the shortest
+/// path found so far through a node reached by both sides along with that node.
+fn perform_bidirectional_step<N, E, G>(graph: &G, heap: &mut FibonacciHeap<HeapKey<E::Weight>, N>, metadata: &mut HashMap<N, MetadataDijsktra<N, E::Weight>>, other: &HashMap<N, MetadataDijsktra<N, E::Weight>>, best: &mut Option<(E::Weight, N)>, reverse: bool) -> Result<SearchStep, GraphError<N>>
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>,
+          G: Graph<N, E>
+{
+    if heap.minimum().is_none() {
+        return Ok(SearchStep::Exhausted);
+    }
+    
+    let min_id: N = heap.extract_min().unwrap().1;
+    
+    match metadata.get_mut(&min_id) {
+        Some(ref mut x) => x.visited = true,
+        None => return Err(GraphError::InternalInconsistency(min_id.clone()))
+    }
+    
+    let met = match other.get(&min_id) {
+        Some(x) => x.visited,
+        None => return Err(GraphError::InternalInconsistency(min_id.clone()))
+    };
+    
+    let mut touched: Vec<N> = vec![min_id.clone()];
+    
+    if !met {
+        let edges = if reverse { graph.predecessors(&min_id) } else { graph.neighbours(&min_id) };
+        try!(perform_edge_relaxation(edges, metadata, heap, &min_id, &no_heuristic));
+        
+        let edges = if reverse { graph.predecessors(&min_id) } else { graph.neighbours(&min_id) };
+        touched.extend(edges.map(|(id, _)| id.clone()));
+    }
+    
+    for id in touched.iter() {
+        let (near, far) = match (metadata.get(id), other.get(id)) {
+            (Some(x), Some(y)) => match (x.distance, y.distance) {
+                (Some(near), Some(far)) => (near, far),
+                _ => continue
+            },
+            _ => return Err(GraphError::InternalInconsistency(id.clone()))
+        };
+        
+        let length = match near.checked_add(&far) {
+            Some(x) => x,
+            None => return Err(GraphError::WeightOverflow(min_id.clone(), id.clone()))
+        };
+        
+        let improved = match *best {
+            Some((ref distance, _)) => length < *distance,
+            None => true
+        };
+        
+        if improved {
+            *best = Some((length, id.clone()));
+        }
+    }
+    
+    Ok(if met { SearchStep::Met } else { SearchStep::Continue })
+}
+
+/// Creates a graph of the same type holding only the given nodes and edges of a graph, with the node and edge payloads
+/// copied across.
+fn create_subgraph<'a, N, E, G, I>(graph: &G, nodes: I, edges: &Vec<E>) -> G
+    where N: 'a + Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>,
+          G: Graph<N, E>,
+          G::NodeData: Clone,
+          G::EdgeData: Clone,
+          I: Iterator<Item=&'a N>
+{
+    let mut result: G = Graph::new();
+    
+    for n in nodes {
+        result.add_node(n.clone());
+        
+        match graph.node_data(n) {
+            Some(data) => { let _ = result.set_node_data(n, data.clone()); },
+            None => ()
+        }
+    }
+    
+    for e in edges {
+        result.add_edge_with_cost(e.get_source(), e.get_target(), e.get_weight(), e.get_cost());
+        
+        match graph.edge_data(e.source(), e.target()) {
+            Some(data) => { let _ = result.set_edge_data(e.source(), e.target(), data.clone()); },
+            None => ()
+        }
+    }
+    
+    result
+}
+
+/// Selects the edges of a minimum spanning forest of a graph using Kruskal's algorithm, or of a maximum spanning forest
+/// if `descending` is set.
+fn find_kruskal_edges<N, E, G>(graph: &G, descending: bool) -> Vec<E>
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>,
+          G: Graph<N, E>
+{
+    let mut node_set: DisjointSet<N> = DisjointSet::new();
+    let mut mst_edges = Vec::new();
+    let mut edges: Vec<&E> = graph.edges().collect();
+    let n = graph.nodes().count().saturating_sub(1);
+    
+    if descending {
+        edges.sort_by(|a, b| compare_weights(&b.get_weight(), &a.get_weight()));
+    } else {
+        edges.sort_by(|a, b| compare_weights(&a.get_weight(), &b.get_weight()));
+    }
+    
+    for n in graph.nodes() {
+        node_set.make_set(n.clone());
+    }
+    
+    for e in edges {
+        if mst_edges.len() == n {
+            break;
+        }
+        
+        if node_set.find(e.get_target()) != node_set.find(e.get_source()) {
+            mst_edges.push(e.clone());
+            node_set.union(e.get_target(), e.get_source());
+        }
+    }
+    
+    mst_edges
+}
+
+/// Returns the weight of the lightest edge from one node to another.
+fn get_path_edge_weight<N, E, G>(graph: &G, source: &N, destination: &N) -> Result<E::Weight, GraphError<N>>
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>,
+          G: Graph<N, E>
+{
+    let mut weight: Option<E::Weight> = None;
+    
+    for (id, edge) in graph.neighbours(source) {
+        if *id == *destination {
+            weight = match weight {
+                Some(x) if x <= edge.get_weight() => Some(x),
+                _ => Some(edge.get_weight())
+            };
+        }
+    }
+    
+    match weight {
+        Some(x) => Ok(x),
+        None => Err(GraphError::EdgeNotFound(source.clone(), destination.clone()))
+    }
+}
+
+fn check_non_negative_weights<N, E, G>(graph: &G) -> Result<(), GraphError<N>>
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>,
+          G: Graph<N, E>
+{
+    let zero: E::Weight = Weight::zero();
+    
+    for e in graph.edges() {
+        if e.get_weight() < zero {
+            return Err(GraphError::NegativeWeight(e.get_source(), e.get_target()));
+        }
+    }
+    
+    Ok(())
+}
+
+/// Runs the passes of the Bellman-Ford algorithm from the distances already in the metadata.
+///
+/// Returns `GraphError::NegativeCycle` if distances are still being lowered after V passes.
+fn perform_bellman_ford<N, E, G>(graph: &G, metadata: &mut HashMap<N, MetadataDijsktra<N, E::Weight>>) -> Result<(), GraphError<N>>
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>,
+          G: Graph<N, E>
+{
+    let node_count = graph.nodes().count();
+    
+    for i in 0..node_count {
+        match try!(perform_bellman_ford_pass(graph, metadata)) {
+            Some(updated) => {
+                if i == node_count - 1 {
+                    return Err(GraphError::NegativeCycle(try!(find_predecessor_cycle(metadata, &updated, node_count))));
+                }
+            },
+            None => break
+        }
+    }
+    
+    Ok(())
+}
+
+/// Relaxes every edge in the graph once, returning the last node whose distance was lowered, if any.
+fn perform_bellman_ford_pass<N, E, G>(graph: &G, metadata: &mut HashMap<N, MetadataDijsktra<N, E::Weight>>) -> Result<Option<N>, GraphError<N>>
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>,
+          G: Graph<N, E>
+{
+    let mut updated: Option<N> = None;
+    
+    for u in graph.nodes() {
+        let distance;
+        match metadata.get(u) {
+            Some(&MetadataDijsktra { distance: Some(x), .. }) => distance = x,
+            Some(_) => continue,
+            None => return Err(GraphError::InternalInconsistency(u.clone()))
+        }
+        
+        for (v, edge) in graph.neighbours(u) {
+            let length = match distance.checked_add(&edge.get_weight()) {
+                Some(x) => x,
+                None => return Err(GraphError::WeightOverflow(u.clone(), v.clone()))
+            };
+            
+            match metadata.get_mut(v) {
+                Some(x) => {
+                    let is_shorter = match x.distance {
+                        Some(d) => length < d,
+                        None => true
+                    };
+                    
+                    if is_shorter {
+                        x.distance = Some(length);
+                        x.predecessor = Some(u.clone());
+                        updated = Some(v.clone());
+                    }
+                },
+                None => return Err(GraphError::InternalInconsistency(v.clone()))
+            }
+        }
+    }
+    
+    Ok(updated)
+}
+
+/// Follows the predecessors from a node that was still being relaxed after V passes of Bellman-Ford to the cycle behind it.
+fn find_predecessor_cycle<N, W>(metadata: &HashMap<N, MetadataDijsktra<N, W>>, start_vertex: &N, node_count: usize) -> Result<Vec<N>, GraphError<N>>
     where N: Eq + Clone + Hash
 {
-    let mut result: GraphPath<N> = GraphPath::new();
+    let predecessor = |id: &N| -> Result<N, GraphError<N>> {
+        match metadata.get(id) {
+            Some(&MetadataDijsktra { predecessor: Some(ref x), .. }) => Ok(x.clone()),
+            _ => Err(GraphError::InternalInconsistency(id.clone()))
+        }
+    };
+    
+    let mut on_cycle: N = start_vertex.clone();
+    for _ in 0..node_count {
+        on_cycle = try!(predecessor(&on_cycle));
+    }
+    
+    let mut cycle: Vec<N> = vec![on_cycle.clone()];
+    let mut last: N = try!(predecessor(&on_cycle));
+    
+    while last != on_cycle {
+        cycle.push(last.clone());
+        last = try!(predecessor(&last));
+    }
+    
+    cycle.reverse();
+    
+    Ok(cycle)
+}
+
+/// Finds a negative cycle on the walk from `start` to `via` and back, which Floyd-Warshall has just found to be negative.
+///
+/// Both halves of the walk are read from the next hops of the matrix and are shortest paths, but they can cross each
+/// other. The walk is split into simple cycles at every repeated node until one of them is negative. Negative self loops
+/// are caught before the main loop of Floyd-Warshall, so the walk always leaves `start`.
+fn find_matrix_cycle<N, E, G>(graph: &G, matrix: &DistanceMatrix<N, E::Weight>, start: usize, via: usize) -> Result<Vec<N>, GraphError<N>>
+    where N: Eq + Clone + Hash,
+          E: Eq + Clone + Hash + Edge<N>,
+          G: Graph<N, E>
+{
+    let n = matrix.nodes.len();
+    let mut walk: Vec<usize> = Vec::new();
+    let mut current = start;
+    
+    for &target in [via, start].iter() {
+        while current != target {
+            match matrix.next[current * n + target] {
+                Some(x) if walk.len() < 2 * n => {
+                    walk.push(x);
+                    current = x;
+                },
+                _ => return Err(GraphError::InternalInconsistency(matrix.nodes[current].clone()))
+            }
+        }
+    }
+    
+    let mut cycle: Vec<usize> = vec![start];
+    let mut lengths: Vec<E::Weight> = vec![Weight::zero()];
+    
+    for &id in walk.iter() {
+        let last = cycle[cycle.len() - 1];
+        let weight = try!(get_path_edge_weight(graph, &matrix.nodes[last], &matrix.nodes[id]));
+        
+        let length = match lengths[lengths.len() - 1].checked_add(&weight) {
+            Some(x) => x,
+            None => return Err(GraphError::WeightOverflow(matrix.nodes[last].clone(), matrix.nodes[id].clone()))
+        };
+        
+        match cycle.iter().position(|&x| x == id) {
+            Some(p) => {
+                let cycle_length = match length.checked_sub(&lengths[p]) {
+                    Some(x) => x,
+                    None => return Err(GraphError::WeightOverflow(matrix.nodes[last].clone(), matrix.nodes[id].clone()))
+                };
+                
+                if cycle_length < Weight::zero() {
+                    return Ok(cycle[p..].iter().map(|&x| matrix.nodes[x].clone()).collect());
+                }
+                
+                cycle.truncate(p + 1);
+                lengths.truncate(p + 1);
+            },
+            None => {
+                cycle.push(id);
+                lengths.push(length);
+            }
+        }
+    }
+    
+    Err(GraphError::InternalInconsistency(matrix.nodes[start].clone()))
+}
+
+fn backtrack_vertex_predecessor<N, W>(metadata: &HashMap<N, MetadataDijsktra<N, W>>, start_vertex: &N, target_vertex: &N) -> Result<GraphPath<N, W>, GraphError<N>>
+    where N: Eq + Clone + Hash,
+          W: Weight
+{
+    let mut result: GraphPath<N, W> = GraphPath::new();
     
     match metadata.get(target_vertex) {
-        Some(ref x) => result.set_distance(x.distance),
-        None => return Err("An error occured while backtracking from a vertex".to_string())
+        Some(ref x) => {
+            match x.distance {
+                Some(distance) => result.set_distance(distance),
+                None => return Err(GraphError::Unreachable(start_vertex.clone(), target_vertex.clone()))
+            }
+        },
+        None => return Err(GraphError::InternalInconsistency(target_vertex.clone()))
     }
     
+    result.set_path(try!(backtrack_predecessors(metadata, start_vertex, target_vertex)));
+    
+    Ok(result)
+}
+
+/// Follows the predecessors recorded in the metadata back from the target vertex, returning the path to it.
+fn backtrack_predecessors<N, W>(metadata: &HashMap<N, MetadataDijsktra<N, W>>, start_vertex: &N, target_vertex: &N) -> Result<Vec<N>, GraphError<N>>
+    where N: Eq + Clone + Hash
+{
     let mut path: Vec<N> = Vec::new();
     let mut last: &N = target_vertex;
     
@@ -457,17 +3555,15 @@ fn backtrack_vertex_predecessor<N>(metadata: &HashMap<N, MetadataDijsktra<N>>, s
             Some(ref x) =>  
                 match x.predecessor {
                     Some(ref y) => last = y,
-                    None => return Err("An error occured while backtracking from a vertex".to_string())
+                    None => return Err(GraphError::InternalInconsistency(last.clone()))
                 },
-            None => return Err("An error occured while backtracking from a vertex".to_string())
+            None => return Err(GraphError::InternalInconsistency(last.clone()))
         }
     }
     
     path.insert(0, start_vertex.clone());   
     
-    result.set_path(path);
-    
-    Ok(result)
+    Ok(path)
 }
 
 fn get_next_vertex<N>(buckets: &mut HashMap<i32, HashSet<N>>, current_core: &mut i32) -> Result<N, ()> 
@@ -3,9 +3,15 @@ extern crate graph;
 use graph::Graph;
 use graph::Edge;
 use graph::graphs::UndirectedAdjacencyListGraph;
+use graph::graphs::DirectedAdjacencyListGraph;
 use graph::graphs::WeightedEdge;
 use graph::graphs::UnweightedEdge;
 use graph::GraphPath;
+use graph::GraphError;
+use graph::Weight;
+use graph::DfsEvent;
+use graph::TraversalStep;
+use graph::MaxFlowAlgorithm;
 use std::collections::HashMap;
 
 #[test]
@@ -27,6 +33,12 @@ fn test_add_edge () {
     graph.add_edge(1, 2, 0);
     
     assert!(graph.is_adjacent(&1, &2));
+    
+    graph.add_edge(1, 3, 0);
+    
+    assert!(!graph.is_adjacent(&1, &3));
+    assert_eq!(graph.degree(&1).unwrap(), 1);
+    assert_eq!(graph.edges().count(), 1);
 }
 
 #[test]
@@ -284,4 +296,1700 @@ fn test_kruskal_min_spanning_tree() {
     assert!(mst.get_edge(&2, &5).is_err());
     assert!(mst.get_edge(&3, &5).is_err());
     assert!(mst.get_edge(&4, &5).is_err());
+}
+
+#[test]
+fn test_directed_add_edge () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    
+    graph.add_edge(1, 2, 10);
+    
+    assert!(graph.is_adjacent(&1, &2));
+    assert!(!graph.is_adjacent(&2, &1));
+    assert!(graph.get_edge(&1, &2).unwrap().is_directed());
+    assert!(graph.get_edge(&2, &1).is_err());
+}
+
+#[test]
+fn test_directed_get_degree () {
+    let mut graph: DirectedAdjacencyListGraph<i32, UnweightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    
+    graph.add_edge(1, 2, 0);
+    graph.add_edge(1, 3, 0);
+    graph.add_edge(3, 1, 0);
+    
+    assert_eq!(graph.out_degree(&1).unwrap(), 2);
+    assert_eq!(graph.in_degree(&1).unwrap(), 1);
+    assert_eq!(graph.degree(&1).unwrap(), 3);
+    assert_eq!(graph.out_degree(&2).unwrap(), 0);
+    assert_eq!(graph.in_degree(&2).unwrap(), 1);
+    assert_eq!(graph.get_node_predecessors(&1), vec![3]);
+}
+
+#[test]
+fn test_directed_dijsktras_shortest_path () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    
+    graph.add_edge(1, 2, 5);
+    graph.add_edge(2, 3, 6);
+    graph.add_edge(3, 1, 1);
+    graph.add_edge(1, 3, 20);
+    graph.add_edge(3, 4, 3);
+    
+    let shortest_path = graph.dijkstras_shortest_path(&1, &4).unwrap();
+    
+    assert_eq!(shortest_path.get_distance(), 14);
+    assert_eq!(*shortest_path.get_path(), vec![1, 2, 3, 4]);
+    
+    let reverse_path = graph.dijkstras_shortest_path(&3, &2).unwrap();
+    
+    assert_eq!(reverse_path.get_distance(), 6);
+    assert_eq!(*reverse_path.get_path(), vec![3, 1, 2]);
+    
+    assert_eq!(graph.dijkstras_shortest_path(&4, &1).unwrap_err(), GraphError::Unreachable(4, 1));
+}
+
+#[test]
+fn test_remove_edge () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    
+    graph.add_edge(1, 2, 4);
+    graph.add_edge(2, 3, 5);
+    
+    assert!(graph.remove_edge(&2, &1).is_ok());
+    
+    assert!(!graph.is_adjacent(&1, &2));
+    assert!(!graph.is_adjacent(&2, &1));
+    assert_eq!(graph.get_edges().len(), 1);
+    assert_eq!(graph.degree(&2).unwrap(), 1);
+    
+    assert!(graph.remove_edge(&1, &2).is_err());
+    assert!(graph.remove_edge(&1, &4).is_err());
+}
+
+#[test]
+fn test_remove_node () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    
+    graph.add_edge(1, 2, 4);
+    graph.add_edge(2, 3, 5);
+    graph.add_edge(3, 1, 6);
+    
+    assert!(graph.remove_node(&2).is_ok());
+    
+    assert!(!graph.is_node_in_graph(&2));
+    assert_eq!(graph.get_edges().len(), 1);
+    assert_eq!(graph.degree(&1).unwrap(), 1);
+    assert_eq!(graph.degree(&3).unwrap(), 1);
+    assert_eq!(graph.get_node_neighbours(&1), vec![3]);
+    
+    assert!(graph.remove_node(&2).is_err());
+}
+
+#[test]
+fn test_directed_remove_node_and_edge () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    
+    graph.add_edge(1, 2, 4);
+    graph.add_edge(2, 3, 5);
+    graph.add_edge(3, 1, 6);
+    graph.add_edge(1, 3, 7);
+    
+    assert!(graph.remove_edge(&3, &1).is_ok());
+    assert!(graph.is_adjacent(&1, &3));
+    assert_eq!(graph.in_degree(&1).unwrap(), 0);
+    
+    assert!(graph.remove_node(&2).is_ok());
+    assert_eq!(graph.get_edges().len(), 1);
+    assert_eq!(graph.out_degree(&1).unwrap(), 1);
+    assert_eq!(graph.in_degree(&3).unwrap(), 1);
+}
+
+#[test]
+fn test_graph_errors () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    
+    graph.add_edge(1, 2, 4);
+    
+    assert_eq!(graph.get_edge(&1, &3).unwrap_err(), GraphError::EdgeNotFound(1, 3));
+    assert_eq!(graph.get_edge(&4, &1).unwrap_err(), GraphError::NodeNotFound(4));
+    assert_eq!(graph.degree(&4).unwrap_err(), GraphError::NodeNotFound(4));
+    assert_eq!(graph.remove_edge(&2, &3).unwrap_err(), GraphError::EdgeNotFound(2, 3));
+    assert_eq!(graph.dijkstras_shortest_path(&1, &4).unwrap_err(), GraphError::NodeNotFound(4));
+    assert_eq!(graph.dijkstras_shortest_path(&1, &3).unwrap_err(), GraphError::Unreachable(1, 3));
+    assert_eq!(graph.dijkstras_shortest_paths(&1).unwrap().get(&3).unwrap().get_path().len(), 0);
+    
+    assert_eq!(format!("{}", GraphError::EdgeNotFound(1, 3)), "no edge exists from 1 to 3");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Cost(u8);
+
+impl Weight for Cost {
+    fn zero() -> Cost { Cost(0) }
+    
+    fn max_value() -> Cost { Cost(255) }
+    
+    fn min_value() -> Cost { Cost(0) }
+    
+    fn checked_add(&self, other: &Cost) -> Option<Cost> {
+        self.0.checked_add(other.0).map(Cost)
+    }
+    
+    fn checked_sub(&self, other: &Cost) -> Option<Cost> {
+        self.0.checked_sub(other.0).map(Cost)
+    }
+    
+    fn checked_mul(&self, other: &Cost) -> Option<Cost> {
+        self.0.checked_mul(other.0).map(Cost)
+    }
+}
+
+#[test]
+fn test_dijsktras_shortest_path_float_weights () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32, f64>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    
+    graph.add_edge(1, 2, 0.5);
+    graph.add_edge(2, 3, 0.25);
+    graph.add_edge(1, 3, 1.0);
+    
+    let shortest_path: GraphPath<i32, f64> = graph.dijkstras_shortest_path(&1, &3).unwrap();
+    
+    assert_eq!(shortest_path.get_distance(), 0.75);
+    assert_eq!(*shortest_path.get_path(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_nan_weights () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32, f64>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    
+    graph.add_edge(1, 2, ::std::f64::NAN);
+    
+    assert!(!graph.is_adjacent(&1, &2));
+    assert_eq!(graph.edges().count(), 0);
+    
+    let edge: WeightedEdge<i32, f64> = Edge::new(1, 2, ::std::f64::NAN, true);
+    
+    assert_eq!(edge, edge.clone());
+}
+
+#[test]
+fn test_kruskal_min_spanning_tree_u64_weights () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32, u64>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    
+    graph.add_edge(1, 2, 10_000_000_000);
+    graph.add_edge(2, 3, 1);
+    graph.add_edge(1, 3, 5_000_000_000);
+    
+    let mst: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32, u64>> = graph.kruskal_min_spanning_tree();
+    
+    assert!(mst.get_edge(&2, &3).is_ok());
+    assert!(mst.get_edge(&1, &3).is_ok());
+    assert!(mst.get_edge(&1, &2).is_err());
+}
+
+#[test]
+fn test_user_defined_weights () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32, Cost>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    
+    graph.add_edge(1, 2, Cost(3));
+    graph.add_edge(2, 3, Cost(4));
+    graph.add_edge(1, 3, Cost(9));
+    graph.add_edge(3, 4, Cost(250));
+    
+    assert_eq!(graph.dijkstras_shortest_path(&1, &3).unwrap().get_distance(), Cost(7));
+    assert_eq!(graph.dijkstras_shortest_path(&1, &4).unwrap_err(), GraphError::WeightOverflow(3, 4));
+}
+
+#[test]
+fn test_distances_equal_to_max_value () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32, u8>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    
+    graph.add_edge(1, 2, 200);
+    graph.add_edge(2, 3, 55);
+    
+    assert_eq!(graph.dijkstras_shortest_path(&1, &3).unwrap().get_distance(), 255);
+    assert_eq!(graph.bidirectional_dijkstra(&1, &3).unwrap().get_distance(), 255);
+    assert_eq!(*graph.bellman_ford_shortest_paths(&1).unwrap()[&3].get_path(), vec![1, 2, 3]);
+    assert_eq!(graph.floyd_warshall().unwrap().get_distance(&1, &3), Some(255));
+    assert_eq!(graph.dijkstras_all_pairs_shortest_paths().unwrap().get_distance(&1, &3), Some(255));
+    assert_eq!(graph.dijkstras_shortest_path(&3, &1).unwrap_err(), GraphError::Unreachable(3, 1));
+    
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32, f64>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    
+    graph.add_edge(1, 2, ::std::f64::INFINITY);
+    
+    assert_eq!(graph.dijkstras_shortest_path(&1, &2).unwrap().get_distance(), ::std::f64::INFINITY);
+    assert_eq!(graph.floyd_warshall().unwrap().get_distance(&1, &2), Some(::std::f64::INFINITY));
+}
+
+#[test]
+fn test_iterators () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    
+    graph.add_edge(1, 2, 4);
+    graph.add_edge(1, 3, 5);
+    
+    let mut nodes: Vec<&i32> = graph.nodes().collect();
+    nodes.sort();
+    assert_eq!(nodes, vec![&1, &2, &3]);
+    
+    let mut neighbours: Vec<(i32, i32)> = graph.neighbours(&1).map(|(n, e)| (*n, e.get_weight())).collect();
+    neighbours.sort();
+    assert_eq!(neighbours, vec![(2, 4), (3, 5)]);
+    
+    let neighbours: Vec<(&i32, &WeightedEdge<i32>)> = graph.neighbours(&3).collect();
+    assert_eq!(neighbours.len(), 1);
+    assert_eq!(*neighbours[0].0, 1);
+    assert_eq!(*neighbours[0].1.source(), 3);
+    
+    assert_eq!(graph.neighbours(&4).count(), 0);
+    assert_eq!(graph.edges().count(), 2);
+    assert_eq!(graph.edges().map(|e| e.get_weight()).fold(0, |a, b| a + b), 9);
+}
+
+#[test]
+fn test_node_and_edge_data () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>, String, u64> = Graph::new();
+    
+    graph.add_node_with_data(1, "one".to_string());
+    graph.add_node_with_data(2, "two".to_string());
+    graph.add_node(3);
+    
+    graph.add_edge_with_data(1, 2, 4, 100);
+    graph.add_edge(2, 3, 5);
+    
+    assert_eq!(graph.node_data(&1).unwrap(), "one");
+    assert!(graph.node_data(&3).is_none());
+    assert_eq!(graph.set_node_data(&3, "three".to_string()).unwrap(), None);
+    assert_eq!(graph.set_node_data(&4, "four".to_string()).unwrap_err(), GraphError::NodeNotFound(4));
+    
+    graph.node_data_mut(&2).unwrap().push_str("!");
+    assert_eq!(graph.node_data(&2).unwrap(), "two!");
+    
+    assert_eq!(*graph.edge_data(&1, &2).unwrap(), 100);
+    assert_eq!(*graph.edge_data(&2, &1).unwrap(), 100);
+    *graph.edge_data_mut(&2, &1).unwrap() += 1;
+    assert_eq!(*graph.edge_data(&1, &2).unwrap(), 101);
+    assert_eq!(graph.set_edge_data(&3, &2, 7).unwrap(), None);
+    assert_eq!(graph.set_edge_data(&1, &3, 7).unwrap_err(), GraphError::EdgeNotFound(1, 3));
+    
+    graph.add_edge_with_data(1, 2, 6, 200);
+    assert_eq!(*graph.edge_data(&1, &2).unwrap(), 200);
+    
+    graph.remove_edge(&1, &2).unwrap();
+    assert_eq!(*graph.edge_data(&1, &2).unwrap(), 200);
+    
+    graph.remove_edge(&1, &2).unwrap();
+    assert!(graph.edge_data(&1, &2).is_none());
+    
+    graph.remove_node(&3).unwrap();
+    assert!(graph.node_data(&3).is_none());
+    assert!(graph.edge_data(&2, &3).is_none());
+}
+
+#[test]
+fn test_kruskal_min_spanning_tree_keeps_data () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>, &str, &str> = Graph::new();
+    
+    graph.add_node_with_data(1, "a");
+    graph.add_node_with_data(2, "b");
+    graph.add_node(3);
+    
+    graph.add_edge_with_data(1, 2, 1, "cheap");
+    graph.add_edge_with_data(2, 3, 2, "medium");
+    graph.add_edge_with_data(1, 3, 3, "expensive");
+    
+    let mst: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>, &str, &str> = graph.kruskal_min_spanning_tree();
+    
+    assert_eq!(*mst.node_data(&1).unwrap(), "a");
+    assert_eq!(*mst.node_data(&2).unwrap(), "b");
+    assert!(mst.node_data(&3).is_none());
+    assert_eq!(*mst.edge_data(&1, &2).unwrap(), "cheap");
+    assert_eq!(*mst.edge_data(&2, &3).unwrap(), "medium");
+    assert!(mst.edge_data(&1, &3).is_none());
+}
+
+#[test]
+fn test_bfs () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, UnweightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    graph.add_node(5);
+    
+    graph.add_edge(1, 2, 0);
+    graph.add_edge(1, 3, 0);
+    graph.add_edge(2, 4, 0);
+    graph.add_edge(3, 4, 0);
+    
+    let steps: Vec<TraversalStep<i32>> = graph.bfs(&1).collect();
+    
+    assert_eq!(steps.iter().map(|s| *s.get_node()).collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+    assert_eq!(steps.iter().map(|s| s.get_depth()).collect::<Vec<u32>>(), vec![0, 1, 1, 2]);
+    assert_eq!(steps[0].get_parent(), None);
+    assert_eq!(steps[3].get_parent(), Some(&2));
+    
+    assert_eq!(graph.bfs(&5).count(), 1);
+    assert_eq!(graph.bfs(&6).count(), 0);
+}
+
+#[test]
+fn test_dfs () {
+    let mut graph: DirectedAdjacencyListGraph<i32, UnweightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    
+    graph.add_edge(1, 2, 0);
+    graph.add_edge(1, 4, 0);
+    graph.add_edge(2, 3, 0);
+    graph.add_edge(3, 4, 0);
+    
+    let steps: Vec<TraversalStep<i32>> = graph.dfs(&1).collect();
+    
+    assert_eq!(steps.iter().map(|s| *s.get_node()).collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+    assert_eq!(steps.iter().map(|s| s.get_depth()).collect::<Vec<u32>>(), vec![0, 1, 2, 3]);
+    assert_eq!(steps[3].get_parent(), Some(&3));
+    
+    assert_eq!(graph.dfs(&4).count(), 1);
+}
+
+#[test]
+fn test_dfs_visit_directed () {
+    let mut graph: DirectedAdjacencyListGraph<i32, UnweightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    
+    graph.add_edge(1, 2, 0);
+    graph.add_edge(2, 3, 0);
+    graph.add_edge(3, 1, 0);
+    graph.add_edge(1, 3, 0);
+    graph.add_edge(1, 4, 0);
+    graph.add_edge(4, 2, 0);
+    
+    let mut events = Vec::new();
+    graph.dfs_visit(&1, |e| events.push(e)).unwrap();
+    
+    assert_eq!(events, vec![
+        DfsEvent::Discover(1),
+        DfsEvent::TreeEdge(1, 2),
+        DfsEvent::Discover(2),
+        DfsEvent::TreeEdge(2, 3),
+        DfsEvent::Discover(3),
+        DfsEvent::BackEdge(3, 1),
+        DfsEvent::Finish(3),
+        DfsEvent::Finish(2),
+        DfsEvent::ForwardEdge(1, 3),
+        DfsEvent::TreeEdge(1, 4),
+        DfsEvent::Discover(4),
+        DfsEvent::CrossEdge(4, 2),
+        DfsEvent::Finish(4),
+        DfsEvent::Finish(1)
+    ]);
+    
+    assert_eq!(graph.dfs_visit(&5, |_| ()).unwrap_err(), GraphError::NodeNotFound(5));
+}
+
+#[test]
+fn test_dfs_visit_undirected () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, UnweightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    
+    graph.add_edge(1, 2, 0);
+    graph.add_edge(2, 3, 0);
+    graph.add_edge(3, 1, 0);
+    graph.add_edge(3, 4, 0);
+    
+    let mut tree_edges = 0;
+    let mut back_edges = Vec::new();
+    graph.dfs_visit(&1, |e| match e {
+        DfsEvent::TreeEdge(_, _) => tree_edges += 1,
+        DfsEvent::BackEdge(u, v) => back_edges.push((u, v)),
+        DfsEvent::ForwardEdge(_, _) | DfsEvent::CrossEdge(_, _) => panic!("undirected graphs have no forward or cross edges"),
+        _ => ()
+    }).unwrap();
+    
+    assert_eq!(tree_edges, 3);
+    assert_eq!(back_edges, vec![(3, 1)]);
+}
+
+#[test]
+fn test_connected_components () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, UnweightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    graph.add_node(5);
+    
+    graph.add_edge(1, 2, 0);
+    graph.add_edge(2, 3, 0);
+    graph.add_edge(4, 5, 0);
+    
+    let components = graph.connected_components();
+    
+    assert_eq!(components.count(), 2);
+    assert_eq!(components.get_component(&1), components.get_component(&3));
+    assert!(components.get_component(&1) != components.get_component(&4));
+    assert_eq!(components.get_component(&6), None);
+    
+    let mut members = components.get_components()[components.get_component(&5).unwrap()].clone();
+    members.sort();
+    assert_eq!(members, vec![4, 5]);
+    
+    assert!(!graph.is_connected());
+    graph.add_edge(3, 4, 0);
+    assert!(graph.is_connected());
+}
+
+#[test]
+fn test_connected_components_directed () {
+    let mut graph: DirectedAdjacencyListGraph<i32, UnweightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    
+    graph.add_edge(1, 2, 0);
+    graph.add_edge(3, 2, 0);
+    
+    assert!(graph.is_connected());
+    
+    let empty: DirectedAdjacencyListGraph<i32, UnweightedEdge<i32>> = Graph::new();
+    assert_eq!(empty.connected_components().count(), 0);
+    assert!(empty.is_connected());
+}
+
+#[test]
+fn test_bellman_ford_shortest_paths () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    graph.add_node(5);
+    
+    graph.add_edge(1, 2, 4);
+    graph.add_edge(1, 3, 5);
+    graph.add_edge(3, 2, -3);
+    graph.add_edge(2, 4, 2);
+    
+    let shortest_paths = graph.bellman_ford_shortest_paths(&1).unwrap();
+    
+    assert_eq!(shortest_paths.get(&1).unwrap().get_distance(), 0);
+    assert_eq!(shortest_paths.get(&2).unwrap().get_distance(), 2);
+    assert_eq!(*shortest_paths.get(&2).unwrap().get_path(), vec![1, 3, 2]);
+    assert_eq!(shortest_paths.get(&4).unwrap().get_distance(), 4);
+    assert_eq!(*shortest_paths.get(&4).unwrap().get_path(), vec![1, 3, 2, 4]);
+    assert_eq!(shortest_paths.get(&5).unwrap().get_path().len(), 0);
+    
+    assert_eq!(graph.dijkstras_shortest_path(&1, &4).unwrap_err(), GraphError::NegativeWeight(3, 2));
+    assert_eq!(graph.dijkstras_shortest_paths(&1).unwrap_err(), GraphError::NegativeWeight(3, 2));
+}
+
+#[test]
+fn test_bellman_ford_negative_cycle () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    
+    graph.add_edge(1, 2, 1);
+    graph.add_edge(2, 3, 2);
+    graph.add_edge(3, 4, -2);
+    graph.add_edge(4, 2, -1);
+    
+    match graph.bellman_ford_shortest_paths(&1) {
+        Err(GraphError::NegativeCycle(cycle)) => {
+            assert_eq!(cycle.len(), 3);
+            let start = cycle.iter().position(|&x| x == 2).unwrap();
+            assert_eq!(cycle[(start + 1) % 3], 3);
+            assert_eq!(cycle[(start + 2) % 3], 4);
+        },
+        _ => panic!("expected a negative cycle")
+    }
+    
+    let mut undirected: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    undirected.add_node(1);
+    undirected.add_node(2);
+    undirected.add_edge(1, 2, -1);
+    
+    match undirected.bellman_ford_shortest_paths(&1) {
+        Err(GraphError::NegativeCycle(cycle)) => assert_eq!(cycle.len(), 2),
+        _ => panic!("expected a negative cycle")
+    }
+}
+
+#[test]
+fn test_a_star_shortest_path_consistent_heuristic () {
+    let mut graph: UndirectedAdjacencyListGraph<(i32, i32), WeightedEdge<(i32, i32)>> = Graph::new();
+    
+    for x in 0..5 {
+        for y in 0..5 {
+            graph.add_node((x, y));
+        }
+    }
+    
+    for x in 0..5 {
+        for y in 0..5 {
+            if x < 4 { graph.add_edge((x, y), (x + 1, y), if y == 2 { 3 } else { 1 }); }
+            if y < 4 { graph.add_edge((x, y), (x, y + 1), 1); }
+        }
+    }
+    
+    let manhattan = |n: &(i32, i32)| (4 - n.0).abs() + (2 - n.1).abs();
+    
+    let a_star = graph.a_star_shortest_path(&(0, 2), &(4, 2), manhattan).unwrap();
+    let dijkstra = graph.dijkstras_shortest_path(&(0, 2), &(4, 2)).unwrap();
+    
+    assert_eq!(a_star.get_distance(), 6);
+    assert_eq!(a_star.get_distance(), dijkstra.get_distance());
+    assert_eq!(*a_star.get_path().first().unwrap(), (0, 2));
+    assert_eq!(*a_star.get_path().last().unwrap(), (4, 2));
+    assert_eq!(a_star.get_path().len(), 7);
+}
+
+#[test]
+fn test_a_star_shortest_path_inconsistent_heuristic () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    
+    graph.add_edge(1, 2, 1);
+    graph.add_edge(2, 3, 1);
+    graph.add_edge(1, 3, 3);
+    graph.add_edge(3, 4, 5);
+    
+    // Admissible, but not consistent across the edge from 2 to 3, so node 3 is expanded twice.
+    let heuristic = |n: &i32| if *n == 2 { 6 } else { 0 };
+    
+    let shortest_path = graph.a_star_shortest_path(&1, &4, heuristic).unwrap();
+    
+    assert_eq!(shortest_path.get_distance(), 7);
+    assert_eq!(*shortest_path.get_path(), vec![1, 2, 3, 4]);
+    
+    assert_eq!(graph.a_star_shortest_path(&4, &1, heuristic).unwrap_err(), GraphError::Unreachable(4, 1));
+}
+
+#[test]
+fn test_floyd_warshall () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    graph.add_node(5);
+    
+    graph.add_edge(1, 2, 4);
+    graph.add_edge(1, 3, 5);
+    graph.add_edge(3, 2, -3);
+    graph.add_edge(2, 4, 2);
+    graph.add_edge(4, 1, 1);
+    
+    let matrix = graph.floyd_warshall().unwrap();
+    
+    assert_eq!(matrix.get_distance(&1, &4), Some(4));
+    assert_eq!(matrix.get_distance(&4, &2), Some(3));
+    assert_eq!(matrix.get_distance(&3, &3), Some(0));
+    assert_eq!(matrix.get_distance(&1, &5), None);
+    assert_eq!(matrix.get_next_hop(&1, &4), Some(&3));
+    
+    let path = matrix.get_path(&4, &2).unwrap();
+    assert_eq!(path.get_distance(), 3);
+    assert_eq!(*path.get_path(), vec![4, 1, 3, 2]);
+    
+    assert_eq!(matrix.get_path(&5, &1).unwrap_err(), GraphError::Unreachable(5, 1));
+    assert_eq!(matrix.get_path(&6, &1).unwrap_err(), GraphError::NodeNotFound(6));
+    
+    graph.add_edge(2, 3, 1);
+    
+    match graph.floyd_warshall() {
+        Err(GraphError::NegativeCycle(cycle)) => {
+            assert_eq!(cycle.len(), 2);
+            assert!(cycle.contains(&2) && cycle.contains(&3));
+        },
+        _ => panic!("expected a negative cycle")
+    }
+    
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_edge(1, 1, -1);
+    
+    assert_eq!(graph.floyd_warshall().unwrap_err(), GraphError::NegativeCycle(vec![1]));
+    assert_eq!(graph.bellman_ford_shortest_paths(&1).unwrap_err(), GraphError::NegativeCycle(vec![1]));
+}
+
+#[test]
+fn test_floyd_warshall_negative_cycles_random_graphs () {
+    let mut state: u64 = 7;
+    
+    for _ in 0..50 {
+        let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+        let node_count = 3 + (next_random(&mut state) % 10) as i32;
+        
+        for i in 0..node_count {
+            graph.add_node(i);
+        }
+        
+        for _ in 0..(node_count * 2) {
+            let source = (next_random(&mut state) % node_count as u64) as i32;
+            let target = (next_random(&mut state) % node_count as u64) as i32;
+            let weight = (next_random(&mut state) % 60) as i32 - 15;
+            
+            if !graph.is_adjacent(&source, &target) {
+                graph.add_edge(source, target, weight);
+            }
+        }
+        
+        match graph.floyd_warshall() {
+            Ok(_) => assert!(graph.johnson_shortest_paths().is_ok()),
+            Err(GraphError::NegativeCycle(cycle)) => {
+                let mut weight = 0;
+                
+                for i in 0..cycle.len() {
+                    weight += graph.get_edge(&cycle[i], &cycle[(i + 1) % cycle.len()]).unwrap().get_weight();
+                }
+                
+                assert!(weight < 0);
+            },
+            Err(e) => panic!("unexpected error {:?}", e)
+        }
+    }
+}
+
+#[test]
+fn test_dijkstras_all_pairs_shortest_paths () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    
+    graph.add_edge(1, 2, 5);
+    graph.add_edge(1, 3, 10);
+    graph.add_edge(3, 4, 3);
+    graph.add_edge(2, 3, 6);
+    
+    let dijkstra = graph.dijkstras_all_pairs_shortest_paths().unwrap();
+    let floyd_warshall = graph.floyd_warshall().unwrap();
+    
+    for u in 1..5 {
+        for v in 1..5 {
+            assert_eq!(dijkstra.get_distance(&u, &v), floyd_warshall.get_distance(&u, &v));
+            assert_eq!(dijkstra.get_path(&u, &v).unwrap().get_distance(), floyd_warshall.get_distance(&u, &v).unwrap());
+        }
+    }
+    
+    assert_eq!(dijkstra.get_distance(&2, &4), Some(9));
+    assert_eq!(*dijkstra.get_path(&4, &2).unwrap().get_path(), vec![4, 3, 2]);
+}
+
+#[test]
+fn test_johnson_shortest_paths () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    graph.add_node(5);
+    
+    graph.add_edge(1, 2, 4);
+    graph.add_edge(1, 3, 5);
+    graph.add_edge(3, 2, -3);
+    graph.add_edge(2, 4, 2);
+    graph.add_edge(4, 1, 1);
+    graph.add_edge(4, 5, -2);
+    
+    let johnson = graph.johnson_shortest_paths().unwrap();
+    let floyd_warshall = graph.floyd_warshall().unwrap();
+    
+    for u in 1..6 {
+        for v in 1..6 {
+            let path = johnson.get(&u).unwrap().get(&v).unwrap();
+            match floyd_warshall.get_distance(&u, &v) {
+                Some(distance) => {
+                    assert_eq!(path.get_distance(), distance);
+                    assert_eq!(*path.get_path().first().unwrap(), u);
+                    assert_eq!(*path.get_path().last().unwrap(), v);
+                },
+                None => assert_eq!(path.get_path().len(), 0)
+            }
+        }
+    }
+    
+    assert_eq!(*johnson.get(&1).unwrap().get(&5).unwrap().get_path(), vec![1, 3, 2, 4, 5]);
+    assert_eq!(johnson.get(&1).unwrap().get(&5).unwrap().get_distance(), 2);
+    
+    graph.add_edge(2, 3, 1);
+    
+    match graph.johnson_shortest_paths() {
+        Err(GraphError::NegativeCycle(cycle)) => assert_eq!(cycle.len(), 2),
+        _ => panic!("expected a negative cycle")
+    }
+}
+
+fn next_random (state: &mut u64) -> u64 {
+    *state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    *state >> 33
+}
+
+fn check_path_weight<G> (graph: &G, path: &GraphPath<i32, i32>) -> ()
+    where G: Graph<i32, WeightedEdge<i32>>
+{
+    let mut distance = 0;
+    for pair in path.get_path().windows(2) {
+        distance += graph.get_edge(&pair[0], &pair[1]).unwrap().get_weight();
+    }
+    assert_eq!(distance, path.get_distance());
+}
+
+#[test]
+fn test_bidirectional_dijkstra () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    graph.add_node(5);
+    
+    graph.add_edge(1, 2, 7);
+    graph.add_edge(1, 3, 2);
+    graph.add_edge(3, 2, 3);
+    graph.add_edge(2, 4, 1);
+    graph.add_edge(3, 4, 8);
+    
+    let path = graph.bidirectional_dijkstra(&1, &4).unwrap();
+    assert_eq!(path.get_distance(), 6);
+    assert_eq!(*path.get_path(), vec![1, 3, 2, 4]);
+    
+    let path = graph.bidirectional_dijkstra(&2, &2).unwrap();
+    assert_eq!(path.get_distance(), 0);
+    assert_eq!(*path.get_path(), vec![2]);
+    
+    assert_eq!(graph.bidirectional_dijkstra(&4, &1).unwrap_err(), GraphError::Unreachable(4, 1));
+    assert_eq!(graph.bidirectional_dijkstra(&1, &5).unwrap_err(), GraphError::Unreachable(1, 5));
+    assert_eq!(graph.bidirectional_dijkstra(&1, &6).unwrap_err(), GraphError::NodeNotFound(6));
+}
+
+#[test]
+fn test_bidirectional_dijkstra_random_graphs () {
+    let mut state: u64 = 42;
+    
+    for _ in 0..20 {
+        let mut directed: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+        let mut undirected: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+        let node_count = 5 + (next_random(&mut state) % 20) as i32;
+        
+        for i in 0..node_count {
+            directed.add_node(i);
+            undirected.add_node(i);
+        }
+        
+        for _ in 0..(node_count * 2) {
+            let source = (next_random(&mut state) % node_count as u64) as i32;
+            let target = (next_random(&mut state) % node_count as u64) as i32;
+            let weight = (next_random(&mut state) % 100) as i32;
+            
+            if source != target && !directed.is_adjacent(&source, &target) {
+                directed.add_edge(source, target, weight);
+            }
+            
+            if source != target && !undirected.is_adjacent(&source, &target) {
+                undirected.add_edge(source, target, weight);
+            }
+        }
+        
+        for source in 0..node_count {
+            for target in 0..node_count {
+                match directed.dijkstras_shortest_path(&source, &target) {
+                    Ok(expected) => {
+                        let path = directed.bidirectional_dijkstra(&source, &target).unwrap();
+                        assert_eq!(path.get_distance(), expected.get_distance());
+                        check_path_weight(&directed, &path);
+                    },
+                    Err(e) => assert_eq!(directed.bidirectional_dijkstra(&source, &target).unwrap_err(), e)
+                }
+                
+                match undirected.dijkstras_shortest_path(&source, &target) {
+                    Ok(expected) => {
+                        let path = undirected.bidirectional_dijkstra(&source, &target).unwrap();
+                        assert_eq!(path.get_distance(), expected.get_distance());
+                        check_path_weight(&undirected, &path);
+                    },
+                    Err(e) => assert_eq!(undirected.bidirectional_dijkstra(&source, &target).unwrap_err(), e)
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_k_shortest_paths () {
+    let mut graph: DirectedAdjacencyListGraph<char, WeightedEdge<char>> = Graph::new();
+    
+    for c in "CDEFGH".chars() {
+        graph.add_node(c);
+    }
+    
+    graph.add_edge('C', 'D', 3);
+    graph.add_edge('C', 'E', 2);
+    graph.add_edge('D', 'F', 4);
+    graph.add_edge('E', 'D', 1);
+    graph.add_edge('E', 'F', 2);
+    graph.add_edge('E', 'G', 3);
+    graph.add_edge('F', 'G', 2);
+    graph.add_edge('F', 'H', 1);
+    graph.add_edge('G', 'H', 2);
+    
+    let paths = graph.k_shortest_paths(&'C', &'H', 3).unwrap();
+    
+    assert_eq!(paths.len(), 3);
+    assert_eq!(*paths[0].get_path(), vec!['C', 'E', 'F', 'H']);
+    assert_eq!(paths[0].get_distance(), 5);
+    assert_eq!(*paths[1].get_path(), vec!['C', 'E', 'G', 'H']);
+    assert_eq!(paths[1].get_distance(), 7);
+    assert_eq!(*paths[2].get_path(), vec!['C', 'D', 'F', 'H']);
+    assert_eq!(paths[2].get_distance(), 8);
+    
+    let paths = graph.k_shortest_paths(&'C', &'H', 100).unwrap();
+    
+    assert_eq!(paths.len(), 7);
+    for pair in paths.windows(2) {
+        assert!(pair[0].get_distance() <= pair[1].get_distance());
+        assert!(pair[0].get_path() != pair[1].get_path());
+    }
+    
+    assert_eq!(graph.k_shortest_paths(&'H', &'C', 3).unwrap().len(), 0);
+    assert_eq!(graph.k_shortest_paths(&'C', &'H', 0).unwrap().len(), 0);
+}
+
+#[test]
+fn test_prim_min_spanning_tree () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    graph.add_node(5);
+    
+    graph.add_edge(1, 2, 3);
+    graph.add_edge(1, 5, 1);
+    graph.add_edge(2, 3, 5);
+    graph.add_edge(2, 5, 4);
+    graph.add_edge(3, 4, 2);
+    graph.add_edge(3, 5, 6);
+    graph.add_edge(4, 5, 7);
+    
+    let mst: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = graph.prim_min_spanning_tree();
+    
+    assert_eq!(mst.get_nodes().len(), 5);
+    assert_eq!(mst.get_edges().len(), 4);
+    
+    assert!(mst.get_edge(&1, &2).is_ok());
+    assert!(mst.get_edge(&1, &5).is_ok());
+    assert!(mst.get_edge(&2, &3).is_ok());
+    assert!(mst.get_edge(&3, &4).is_ok());
+}
+
+#[test]
+fn test_min_spanning_tree_negative_and_sparse_weights () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    
+    graph.add_edge(1, 2, -5);
+    graph.add_edge(2, 3, 1000000000);
+    graph.add_edge(1, 3, -2);
+    graph.add_edge(3, 4, 1000000000);
+    graph.add_edge(2, 4, 999999999);
+    
+    let kruskal: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = graph.kruskal_min_spanning_tree();
+    let prim: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = graph.prim_min_spanning_tree();
+    
+    for mst in vec![kruskal, prim] {
+        assert_eq!(mst.get_edges().len(), 3);
+        assert_eq!(mst.edges().map(|e| e.get_weight()).sum::<i32>(), 999999992);
+        
+        assert!(mst.get_edge(&1, &2).is_ok());
+        assert!(mst.get_edge(&1, &3).is_ok());
+        assert!(mst.get_edge(&2, &4).is_ok());
+    }
+}
+
+#[test]
+fn test_prim_min_spanning_tree_directed_forest () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>, (), &str> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    graph.add_node(5);
+    
+    graph.add_edge_with_data(2, 1, 4, "a");
+    graph.add_edge_with_data(3, 1, 1, "b");
+    graph.add_edge_with_data(2, 3, 2, "c");
+    graph.add_edge_with_data(4, 5, 7, "d");
+    
+    let mst: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>, (), &str> = graph.prim_min_spanning_tree();
+    
+    assert_eq!(mst.get_edges().len(), 3);
+    assert!(mst.is_adjacent(&3, &1));
+    assert!(mst.is_adjacent(&2, &3));
+    assert!(mst.is_adjacent(&4, &5));
+    assert_eq!(mst.edge_data(&4, &5), Some(&"d"));
+}
+
+#[test]
+fn test_min_spanning_forest () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    assert_eq!(graph.min_spanning_forest().unwrap().len(), 0);
+    assert_eq!(graph.kruskal_min_spanning_tree().get_nodes().len(), 0);
+    
+    graph.add_node(1);
+    
+    let forest = graph.min_spanning_forest().unwrap();
+    assert_eq!(forest.len(), 1);
+    assert_eq!(forest[0].0.get_nodes(), vec![1]);
+    assert_eq!(forest[0].1, 0);
+    
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    graph.add_node(5);
+    graph.add_node(6);
+    
+    graph.add_edge(1, 2, 4);
+    graph.add_edge(2, 3, 1);
+    graph.add_edge(1, 3, 2);
+    graph.add_edge(4, 5, 6);
+    
+    let forest = graph.min_spanning_forest().unwrap();
+    assert_eq!(forest.len(), 3);
+    
+    for &(ref tree, weight) in forest.iter() {
+        let mut nodes = tree.get_nodes();
+        nodes.sort();
+        
+        match nodes[0] {
+            1 => {
+                assert_eq!(nodes, vec![1, 2, 3]);
+                assert_eq!(weight, 3);
+                assert!(tree.is_adjacent(&1, &3));
+                assert!(tree.is_adjacent(&2, &3));
+                assert!(!tree.is_adjacent(&1, &2));
+            },
+            4 => {
+                assert_eq!(nodes, vec![4, 5]);
+                assert_eq!(weight, 6);
+            },
+            6 => {
+                assert_eq!(nodes, vec![6]);
+                assert_eq!(weight, 0);
+            },
+            _ => panic!("unexpected tree")
+        }
+    }
+    
+    assert_eq!(graph.kruskal_min_spanning_tree().get_edges().len(), 3);
+}
+
+#[test]
+fn test_max_spanning_tree () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    
+    graph.add_edge(1, 2, 3);
+    graph.add_edge(2, 3, 1);
+    graph.add_edge(1, 3, 5);
+    graph.add_edge(3, 4, -2);
+    graph.add_edge(2, 4, 4);
+    
+    let mst: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = graph.max_spanning_tree();
+    
+    assert_eq!(mst.get_edges().len(), 3);
+    assert!(mst.is_adjacent(&1, &3));
+    assert!(mst.is_adjacent(&2, &4));
+    assert!(mst.is_adjacent(&1, &2));
+}
+
+#[test]
+fn test_widest_path () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    graph.add_node(5);
+    
+    graph.add_edge(1, 2, 10);
+    graph.add_edge(2, 4, 2);
+    graph.add_edge(1, 3, 4);
+    graph.add_edge(3, 4, 5);
+    graph.add_edge(3, 2, 8);
+    
+    let path = graph.widest_path(&1, &4).unwrap();
+    assert_eq!(path.get_distance(), 4);
+    assert_eq!(*path.get_path(), vec![1, 3, 4]);
+    
+    let path = graph.widest_path(&1, &2).unwrap();
+    assert_eq!(path.get_distance(), 10);
+    assert_eq!(*path.get_path(), vec![1, 2]);
+    
+    let path = graph.widest_path(&1, &1).unwrap();
+    assert_eq!(path.get_distance(), i32::max_value());
+    assert_eq!(*path.get_path(), vec![1]);
+    
+    assert_eq!(graph.widest_path(&4, &1).unwrap_err(), GraphError::Unreachable(4, 1));
+    assert_eq!(graph.widest_path(&1, &5).unwrap_err(), GraphError::Unreachable(1, 5));
+    
+    graph.add_edge(4, 5, i32::min_value());
+    
+    let path = graph.widest_path(&1, &5).unwrap();
+    assert_eq!(path.get_distance(), i32::min_value());
+    assert_eq!(*path.get_path(), vec![1, 3, 4, 5]);
+    
+    let mut unsigned: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32, u32>> = Graph::new();
+    
+    unsigned.add_node(1);
+    unsigned.add_node(2);
+    unsigned.add_node(3);
+    
+    unsigned.add_edge(1, 2, 0);
+    
+    let path = unsigned.widest_path(&1, &2).unwrap();
+    assert_eq!(path.get_distance(), 0);
+    assert_eq!(*path.get_path(), vec![1, 2]);
+    
+    assert_eq!(unsigned.widest_path(&1, &3).unwrap_err(), GraphError::Unreachable(1, 3));
+}
+
+#[test]
+fn test_strongly_connected_components () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    for i in 1..9 {
+        graph.add_node(i);
+    }
+    
+    graph.add_edge(1, 2, 1);
+    graph.add_edge(2, 3, 1);
+    graph.add_edge(3, 1, 1);
+    graph.add_edge(2, 4, 5);
+    graph.add_edge(3, 4, 2);
+    graph.add_edge(4, 5, 1);
+    graph.add_edge(5, 6, 1);
+    graph.add_edge(6, 4, 1);
+    graph.add_edge(6, 7, 3);
+    graph.add_edge(8, 7, 1);
+    
+    let components = graph.strongly_connected_components();
+    
+    assert_eq!(components.count(), 4);
+    assert_eq!(components.get_component(&1), components.get_component(&2));
+    assert_eq!(components.get_component(&1), components.get_component(&3));
+    assert_eq!(components.get_component(&4), components.get_component(&5));
+    assert_eq!(components.get_component(&4), components.get_component(&6));
+    assert!(components.get_component(&1) != components.get_component(&4));
+    assert_eq!(components.get_component(&9), None);
+    
+    for e in graph.edges() {
+        assert!(components.get_component(e.source()) >= components.get_component(e.target()));
+    }
+    
+    let condensation: DirectedAdjacencyListGraph<usize, WeightedEdge<usize>> = graph.condensation();
+    let first = components.get_component(&1).unwrap();
+    let second = components.get_component(&4).unwrap();
+    let third = components.get_component(&7).unwrap();
+    
+    assert_eq!(condensation.get_nodes().len(), 4);
+    assert_eq!(condensation.get_edges().len(), 3);
+    assert_eq!(condensation.get_edge(&first, &second).unwrap().get_weight(), 2);
+    assert_eq!(condensation.get_edge(&second, &third).unwrap().get_weight(), 3);
+}
+
+#[test]
+fn test_topological_sort () {
+    let mut graph: DirectedAdjacencyListGraph<&str, UnweightedEdge<&str>> = Graph::new();
+    
+    graph.add_node("core");
+    graph.add_node("io");
+    graph.add_node("net");
+    graph.add_node("app");
+    graph.add_node("docs");
+    
+    graph.add_edge("core", "io", 1);
+    graph.add_edge("core", "net", 1);
+    graph.add_edge("io", "net", 1);
+    graph.add_edge("net", "app", 1);
+    graph.add_edge("io", "app", 1);
+    
+    let order = graph.topological_sort().unwrap();
+    assert_eq!(order.len(), 5);
+    
+    for e in graph.edges() {
+        let source = order.iter().position(|x| x == e.source()).unwrap();
+        let target = order.iter().position(|x| x == e.target()).unwrap();
+        assert!(source < target);
+    }
+    
+    assert!(!graph.has_cycle());
+    assert_eq!(graph.find_cycle(), None);
+    
+    graph.add_edge("app", "io", 1);
+    
+    let cycle = graph.find_cycle().unwrap();
+    assert!(graph.has_cycle());
+    assert_eq!(cycle.len(), 3);
+    for i in 0..cycle.len() {
+        assert!(graph.is_adjacent(&cycle[i], &cycle[(i + 1) % cycle.len()]));
+    }
+    
+    match graph.topological_sort() {
+        Err(GraphError::Cycle(x)) => assert_eq!(x.len(), 3),
+        _ => panic!("expected a cycle")
+    }
+}
+
+#[test]
+fn test_find_cycle_undirected () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, UnweightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_node(4);
+    
+    graph.add_edge(1, 2, 1);
+    graph.add_edge(2, 3, 1);
+    graph.add_edge(2, 4, 1);
+    
+    assert!(!graph.has_cycle());
+    assert!(graph.topological_sort().is_ok());
+    
+    graph.add_edge(4, 1, 1);
+    
+    let mut cycle = graph.find_cycle().unwrap();
+    cycle.sort();
+    assert_eq!(cycle, vec![1, 2, 4]);
+}
+
+#[test]
+fn test_articulation_points_and_bridges () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    for i in 1..8 {
+        graph.add_node(i);
+    }
+    
+    graph.add_edge(1, 2, 1);
+    graph.add_edge(2, 3, 2);
+    graph.add_edge(3, 1, 3);
+    graph.add_edge(3, 4, 4);
+    graph.add_edge(4, 5, 5);
+    graph.add_edge(5, 6, 6);
+    graph.add_edge(6, 4, 7);
+    graph.add_edge(6, 7, 8);
+    
+    let mut points = graph.articulation_points();
+    points.sort();
+    assert_eq!(points, vec![3, 4, 6]);
+    
+    let mut bridges: Vec<i32> = graph.bridges().iter().map(|e| e.get_weight()).collect();
+    bridges.sort();
+    assert_eq!(bridges, vec![4, 8]);
+    
+    let mut components: Vec<Vec<i32>> = graph.biconnected_components().iter()
+        .map(|c| {
+            let mut weights: Vec<i32> = c.iter().map(|e| e.get_weight()).collect();
+            weights.sort();
+            weights
+        })
+        .collect();
+    components.sort();
+    assert_eq!(components, vec![vec![1, 2, 3], vec![4], vec![5, 6, 7], vec![8]]);
+}
+
+#[test]
+fn test_bridges_parallel_edges () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.add_node(3);
+    
+    graph.add_edge(1, 2, 1);
+    graph.add_edge(1, 2, 2);
+    graph.add_edge(2, 3, 3);
+    
+    let bridges = graph.bridges();
+    assert_eq!(bridges.len(), 1);
+    assert_eq!(bridges[0].get_weight(), 3);
+    assert_eq!(graph.articulation_points(), vec![2]);
+    
+    let mut components: Vec<Vec<i32>> = graph.biconnected_components().iter()
+        .map(|c| {
+            let mut weights: Vec<i32> = c.iter().map(|e| e.get_weight()).collect();
+            weights.sort();
+            weights
+        })
+        .collect();
+    components.sort();
+    assert_eq!(components, vec![vec![1, 2], vec![3]]);
+}
+
+fn check_eulerian_trail<G> (graph: &G, trail: &Vec<i32>) -> ()
+    where G: Graph<i32, UnweightedEdge<i32>>
+{
+    assert_eq!(trail.len(), graph.get_edges().len() + 1);
+    for pair in trail.windows(2) {
+        assert!(graph.is_adjacent(&pair[0], &pair[1]));
+    }
+}
+
+#[test]
+fn test_eulerian_circuit () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, UnweightedEdge<i32>> = Graph::new();
+    
+    assert_eq!(graph.eulerian_circuit().unwrap().len(), 0);
+    
+    for i in 1..6 {
+        graph.add_node(i);
+    }
+    
+    graph.add_edge(1, 2, 1);
+    graph.add_edge(2, 3, 1);
+    graph.add_edge(3, 1, 1);
+    graph.add_edge(3, 4, 1);
+    graph.add_edge(4, 5, 1);
+    graph.add_edge(5, 3, 1);
+    
+    let circuit = graph.eulerian_circuit().unwrap();
+    check_eulerian_trail(&graph, &circuit);
+    assert_eq!(circuit.first(), circuit.last());
+    
+    graph.add_edge(1, 4, 1);
+    
+    match graph.eulerian_circuit() {
+        Err(GraphError::NotEulerian(mut x)) => {
+            x.sort();
+            assert_eq!(x, vec![1, 4]);
+        },
+        _ => panic!("expected odd degree nodes")
+    }
+    
+    let path = graph.eulerian_path().unwrap();
+    check_eulerian_trail(&graph, &path);
+    assert!((path[0] == 1 && path[7] == 4) || (path[0] == 4 && path[7] == 1));
+    
+    graph.add_node(6);
+    graph.add_node(7);
+    graph.add_edge(6, 7, 1);
+    graph.add_edge(6, 7, 1);
+    graph.remove_edge(&1, &4).unwrap();
+    
+    match graph.eulerian_circuit() {
+        Err(GraphError::Unreachable(_, _)) => (),
+        _ => panic!("expected the edges to be disconnected")
+    }
+}
+
+#[test]
+fn test_eulerian_path_directed () {
+    let mut graph: DirectedAdjacencyListGraph<i32, UnweightedEdge<i32>> = Graph::new();
+    
+    for i in 1..5 {
+        graph.add_node(i);
+    }
+    
+    graph.add_edge(1, 2, 1);
+    graph.add_edge(2, 3, 1);
+    graph.add_edge(3, 1, 1);
+    graph.add_edge(1, 4, 1);
+    
+    assert!(graph.eulerian_circuit().is_err());
+    
+    let path = graph.eulerian_path().unwrap();
+    check_eulerian_trail(&graph, &path);
+    assert_eq!(path, vec![1, 2, 3, 1, 4]);
+    
+    graph.add_edge(4, 1, 1);
+    
+    let circuit = graph.eulerian_circuit().unwrap();
+    check_eulerian_trail(&graph, &circuit);
+    assert_eq!(circuit.first(), circuit.last());
+    
+    graph.add_edge(2, 4, 1);
+    graph.add_edge(3, 4, 1);
+    
+    match graph.eulerian_path() {
+        Err(GraphError::NotEulerian(mut x)) => {
+            x.sort();
+            assert_eq!(x, vec![2, 3, 4]);
+        },
+        _ => panic!("expected unbalanced nodes")
+    }
+}
+
+#[test]
+fn test_max_flow () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32, u64>> = Graph::new();
+    
+    for i in 0..6 {
+        graph.add_node(i);
+    }
+    
+    graph.add_edge(0, 1, 16);
+    graph.add_edge(0, 2, 13);
+    graph.add_edge(2, 1, 4);
+    graph.add_edge(1, 3, 12);
+    graph.add_edge(3, 2, 9);
+    graph.add_edge(2, 4, 14);
+    graph.add_edge(4, 3, 7);
+    graph.add_edge(3, 5, 20);
+    graph.add_edge(4, 5, 4);
+    
+    for algorithm in vec![MaxFlowAlgorithm::EdmondsKarp, MaxFlowAlgorithm::Dinic] {
+        let flow = graph.max_flow(&0, &5, algorithm).unwrap();
+        
+        assert_eq!(flow.get_value(), 23);
+        
+        for node in 1..5 {
+            let incoming: u64 = graph.edges().filter(|e| *e.target() == node).map(|e| flow.get_flow(e).unwrap_or(0)).sum();
+            let outgoing: u64 = graph.edges().filter(|e| *e.source() == node).map(|e| flow.get_flow(e).unwrap_or(0)).sum();
+            assert_eq!(incoming, outgoing);
+        }
+        
+        for e in graph.edges() {
+            assert!(flow.get_flow(e).unwrap_or(0) <= e.get_weight());
+        }
+        
+        assert!(flow.get_flows().values().all(|x| *x > 0));
+        assert!(graph.max_flow(&5, &0, algorithm).unwrap().get_flows().is_empty());
+        assert_eq!(graph.max_flow(&5, &0, algorithm).unwrap().get_value(), 0);
+        assert_eq!(graph.max_flow(&0, &0, algorithm).unwrap().get_value(), 0);
+    }
+    
+    assert_eq!(graph.max_flow(&0, &6, MaxFlowAlgorithm::Dinic).unwrap_err(), GraphError::NodeNotFound(6));
+}
+
+#[test]
+fn test_max_flow_undirected () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    for i in 1..5 {
+        graph.add_node(i);
+    }
+    
+    graph.add_edge(1, 2, 3);
+    graph.add_edge(1, 3, 2);
+    graph.add_edge(3, 2, 5);
+    graph.add_edge(2, 4, 2);
+    graph.add_edge(3, 4, 3);
+    
+    for algorithm in vec![MaxFlowAlgorithm::EdmondsKarp, MaxFlowAlgorithm::Dinic] {
+        let flow = graph.max_flow(&1, &4, algorithm).unwrap();
+        assert_eq!(flow.get_value(), 5);
+        
+        let reversed = flow.get_flow(&WeightedEdge::new(4, 2, 2, false)).unwrap_or(0);
+        let forward = flow.get_flow(&WeightedEdge::new(2, 4, 2, false)).unwrap_or(0);
+        assert_eq!(reversed + forward, 2);
+        assert_eq!(reversed, 0);
+    }
+    
+    graph.add_edge(2, 3, -1);
+    assert_eq!(graph.max_flow(&1, &4, MaxFlowAlgorithm::Dinic).unwrap_err(), GraphError::NegativeWeight(2, 3));
+}
+
+#[test]
+fn test_min_cut () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    for i in 0..6 {
+        graph.add_node(i);
+    }
+    
+    graph.add_edge(0, 1, 16);
+    graph.add_edge(0, 2, 13);
+    graph.add_edge(2, 1, 4);
+    graph.add_edge(1, 3, 12);
+    graph.add_edge(3, 2, 9);
+    graph.add_edge(2, 4, 14);
+    graph.add_edge(4, 3, 7);
+    graph.add_edge(3, 5, 20);
+    graph.add_edge(4, 5, 4);
+    
+    let cut = graph.min_cut(&0, &5).unwrap();
+    
+    let mut source_side = cut.get_source_side().clone();
+    source_side.sort();
+    let mut sink_side = cut.get_sink_side().clone();
+    sink_side.sort();
+    let mut weights: Vec<i32> = cut.get_edges().iter().map(|e| e.get_weight()).collect();
+    weights.sort();
+    
+    assert_eq!(cut.get_weight(), 23);
+    assert_eq!(source_side, vec![0, 1, 2, 4]);
+    assert_eq!(sink_side, vec![3, 5]);
+    assert_eq!(weights, vec![4, 7, 12]);
+}
+
+#[test]
+fn test_min_cut_undirected () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    for i in 1..7 {
+        graph.add_node(i);
+    }
+    
+    graph.add_edge(1, 2, 5);
+    graph.add_edge(2, 3, 5);
+    graph.add_edge(3, 1, 5);
+    graph.add_edge(4, 3, 2);
+    graph.add_edge(2, 5, 1);
+    graph.add_edge(4, 5, 6);
+    graph.add_edge(5, 6, 6);
+    graph.add_edge(6, 4, 6);
+    
+    let cut = graph.min_cut(&1, &6).unwrap();
+    
+    let mut sink_side = cut.get_sink_side().clone();
+    sink_side.sort();
+    
+    assert_eq!(cut.get_weight(), 3);
+    assert_eq!(sink_side, vec![4, 5, 6]);
+    assert_eq!(cut.get_edges().len(), 2);
+    assert_eq!(cut.get_edges().iter().map(|e| e.get_weight()).sum::<i32>(), 3);
+}
+
+#[test]
+fn test_global_min_cut () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    for i in 1..9 {
+        graph.add_node(i);
+    }
+    
+    graph.add_edge(1, 2, 2);
+    graph.add_edge(1, 5, 3);
+    graph.add_edge(2, 3, 3);
+    graph.add_edge(2, 5, 2);
+    graph.add_edge(2, 6, 2);
+    graph.add_edge(3, 4, 4);
+    graph.add_edge(3, 7, 2);
+    graph.add_edge(4, 7, 2);
+    graph.add_edge(4, 8, 2);
+    graph.add_edge(5, 6, 3);
+    graph.add_edge(6, 7, 1);
+    graph.add_edge(7, 8, 3);
+    
+    let cut = graph.global_min_cut().unwrap();
+    
+    let mut sides = vec![cut.get_source_side().clone(), cut.get_sink_side().clone()];
+    for side in sides.iter_mut() {
+        side.sort();
+    }
+    sides.sort();
+    
+    assert_eq!(cut.get_weight(), 4);
+    assert_eq!(sides, vec![vec![1, 2, 5, 6], vec![3, 4, 7, 8]]);
+    assert_eq!(cut.get_edges().iter().map(|e| e.get_weight()).sum::<i32>(), 4);
+}
+
+#[test]
+fn test_global_min_cut_small_graphs () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    let cut = graph.global_min_cut().unwrap();
+    assert_eq!(cut.get_weight(), 0);
+    assert_eq!(cut.get_source_side().len() + cut.get_sink_side().len(), 0);
+    
+    graph.add_node(1);
+    
+    let cut = graph.global_min_cut().unwrap();
+    assert_eq!(cut.get_weight(), 0);
+    assert_eq!(*cut.get_source_side(), vec![1]);
+    
+    graph.add_node(2);
+    graph.add_node(3);
+    graph.add_edge(1, 2, 5);
+    graph.add_edge(1, 2, 4);
+    
+    let cut = graph.global_min_cut().unwrap();
+    assert_eq!(cut.get_weight(), 0);
+    assert_eq!(cut.get_edges().len(), 0);
+    
+    graph.add_edge(2, 3, 7);
+    
+    let cut = graph.global_min_cut().unwrap();
+    assert_eq!(cut.get_weight(), 7);
+    assert_eq!(cut.get_edges().len(), 1);
+}
+
+#[test]
+fn test_min_cost_max_flow () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32, u64>> = Graph::new();
+    
+    for i in 1..5 {
+        graph.add_node(i);
+    }
+    
+    graph.add_edge_with_cost(1, 2, 4, 2);
+    graph.add_edge_with_cost(1, 3, 2, 2);
+    graph.add_edge_with_cost(2, 3, 2, 1);
+    graph.add_edge_with_cost(2, 4, 3, 3);
+    graph.add_edge_with_cost(3, 4, 5, 1);
+    
+    let flow = graph.min_cost_max_flow(&1, &4).unwrap();
+    
+    assert_eq!(flow.get_value(), 6);
+    assert_eq!(flow.get_cost(), 24);
+    assert_eq!(flow.get_flow(&WeightedEdge::with_cost(1, 2, 4, 2, true)), Some(4));
+    assert_eq!(flow.get_flow(&WeightedEdge::with_cost(1, 3, 2, 2, true)), Some(2));
+    assert_eq!(flow.get_flow(&WeightedEdge::with_cost(2, 3, 2, 1, true)), Some(2));
+    assert_eq!(flow.get_flow(&WeightedEdge::with_cost(2, 4, 3, 3, true)), Some(2));
+    assert_eq!(flow.get_flow(&WeightedEdge::with_cost(3, 4, 5, 1, true)), Some(4));
+    
+    let max_flow = graph.max_flow(&1, &4, MaxFlowAlgorithm::Dinic).unwrap();
+    assert_eq!(flow.get_value(), max_flow.get_value());
+    
+    let flow = graph.min_cost_max_flow(&4, &1).unwrap();
+    assert_eq!(flow.get_value(), 0);
+    assert_eq!(flow.get_cost(), 0);
+}
+
+#[test]
+fn test_min_cost_max_flow_parallel_edges () {
+    let mut graph: DirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    for i in 1..4 {
+        graph.add_node(i);
+    }
+    
+    graph.add_edge_with_cost(1, 2, 2, 5);
+    graph.add_edge_with_cost(1, 2, 2, 1);
+    graph.add_edge_with_cost(2, 3, 2, 0);
+    
+    let flow = graph.min_cost_max_flow(&1, &3).unwrap();
+    
+    assert_eq!(flow.get_value(), 2);
+    assert_eq!(flow.get_cost(), 2);
+    assert_eq!(flow.get_flow(&WeightedEdge::with_cost(1, 2, 2, 1, true)), Some(2));
+    assert_eq!(flow.get_flow(&WeightedEdge::with_cost(1, 2, 2, 5, true)), None);
+}
+
+#[test]
+fn test_min_cost_max_flow_undirected () {
+    let mut graph: UndirectedAdjacencyListGraph<i32, WeightedEdge<i32>> = Graph::new();
+    
+    for i in 1..5 {
+        graph.add_node(i);
+    }
+    
+    graph.add_edge_with_cost(1, 2, 2, 1);
+    graph.add_edge_with_cost(2, 4, 2, 1);
+    graph.add_edge_with_cost(1, 3, 2, 1);
+    graph.add_edge_with_cost(3, 4, 2, 1);
+    graph.add_edge_with_cost(3, 2, 1, 10);
+    
+    let flow = graph.min_cost_max_flow(&1, &4).unwrap();
+    
+    assert_eq!(flow.get_value(), 4);
+    assert_eq!(flow.get_cost(), 8);
+    assert_eq!(flow.get_flow(&WeightedEdge::with_cost(3, 2, 1, 10, false)).unwrap_or(0), 0);
+    assert_eq!(flow.get_flow(&WeightedEdge::with_cost(2, 3, 1, 10, false)).unwrap_or(0), 0);
+    
+    graph.add_edge_with_cost(1, 4, 1, -1);
+    
+    assert_eq!(graph.min_cost_max_flow(&1, &4).unwrap_err(), GraphError::NegativeWeight(1, 4));
 }
\ No newline at end of file